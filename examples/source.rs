@@ -11,8 +11,9 @@ fn main() {
 
 	loop {
 		match source.next_char() {
-			Some(ch) => content.push(ch),
-			None => break,
+			Ok(Some(ch)) => content.push(ch),
+			Ok(None) => break,
+			Err(exception) => panic!("{}", exception),
 		}
 	}
 
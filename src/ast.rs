@@ -2,22 +2,54 @@
 
 use crate::preludes::*;
 
-#[derive(Clone, Debug, PartialEq)]
+/// The statements of a module, with where each one starts. Positions are
+/// left out of comparisons.
+#[derive(Clone, Debug)]
 pub struct AbstractSyntaxTree {
 	pub statements: Vec<Statement>,
+	pub positions: Vec<Position>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Block(pub Vec<Statement>);
+/// The statements of a block and, when it was parsed, where each one starts.
+/// Positions are left out of comparisons.
+#[derive(Clone, Debug)]
+pub struct Block(pub Vec<Statement>, pub Vec<Position>);
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Statement {
-	Let(String, Expression),
+	Let(String, Option<String>, Expression),
 	LetAlter(String, Expression),
 	Expression(Expression),
 	ExpressionReturn(Expression),
-	Fn { name: String, params: Vec<String>, body: Block },
-	Import(String),
+	Fn {
+		name: String,
+		params: Vec<Param>,
+		returns: Option<String>,
+		body: Block,
+		is_async: bool,
+	},
+	Import(String, Option<String>),
+	ImportFrom(String, Vec<(String, Option<String>)>),
+	Export(Box<Statement>),
+	Doc(String, Box<Statement>),
+	ModuleDoc(String),
+	Yield(Expression),
+	/// The condition, the message and the source text of the condition.
+	Assert(Expression, Option<Expression>, String),
+	Defer(Expression),
+	With {
+		value: Expression,
+		name: String,
+		body: Block,
+	},
+	For {
+		label: Option<String>,
+		targets: Vec<String>,
+		iter: Expression,
+		body: Block,
+	},
+	Break(Option<String>),
+	Continue(Option<String>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -26,9 +58,11 @@ pub enum Expression {
 	Literal(Literal),
 	Prefix(Prefix, Box<Expression>),
 	Infix(Infix, Box<Expression>, Box<Expression>),
-	Fn { params: Vec<String>, body: Block },
+	Fn { params: Vec<Param>, returns: Option<String>, body: Block, is_async: bool },
+	Await(Box<Expression>),
 	Call { function: Box<Expression>, arguments: Vec<Expression> },
 	Index(Box<Expression>, Box<Expression>),
+	Slice(Box<Expression>, Option<Box<Expression>>, Option<Box<Expression>>),
 	Property(Box<Expression>, Box<Expression>),
 }
 
@@ -39,9 +73,32 @@ pub enum Literal {
 	Float(BigRational),
 	Boolean(bool),
 	String(String),
+	Char(char),
+	Bytes(Vec<u8>),
 	Vec(Vec<Expression>),
 	Tuple(Vec<Expression>),
 	HashMap(Vec<(Expression, Expression)>),
+	Set(Vec<Expression>),
+	VecComprehension(Box<Expression>, Vec<ComprehensionFor>),
+	SetComprehension(Box<Expression>, Vec<ComprehensionFor>),
+	HashMapComprehension(Box<(Expression, Expression)>, Vec<ComprehensionFor>),
+}
+
+/// One `for targets in iter if condition ...` clause of a comprehension. A
+/// single target takes each value as is; several targets unpack it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComprehensionFor {
+	pub targets: Vec<String>,
+	pub iter: Expression,
+	pub conditions: Vec<Expression>,
+}
+
+/// A function parameter with its optional type annotation, one of the names
+/// returned by `Object::typer`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+	pub name: String,
+	pub typer: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -57,12 +114,17 @@ pub enum Infix {
 	Minus,            // -
 	Multiply,         // *
 	Divide,           // /
+	BitOr,            // |
+	BitAnd,           // &
+	BitXor,           // ^
 	Equal,            // ==
 	NotEqual,         // !=
 	LessThanEqual,    // <=
 	LessThan,         // <
 	GreaterThanEqual, // >=
 	GreaterThan,      // >
+	In,               // in
+	NotIn,            // not in
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
@@ -70,7 +132,10 @@ pub enum Precedence {
 	Lowest,
 	Comma,          // ,
 	Equality,       // == or !=
-	Relational,     // > or < or >= or <=
+	Relational,     // > or < or >= or <= or in or not in
+	BitOr,          // |
+	BitXor,         // ^
+	BitAnd,         // &
 	Additive,       // + or -
 	Multiplicative, // / or *
 	Call,           // function(x)
@@ -95,25 +160,301 @@ impl std::fmt::Display for Infix {
 			Infix::Minus => write!(f, "-"),
 			Infix::Multiply => write!(f, "*"),
 			Infix::Divide => write!(f, "/"),
+			Infix::BitOr => write!(f, "|"),
+			Infix::BitAnd => write!(f, "&"),
+			Infix::BitXor => write!(f, "^"),
 			Infix::Equal => write!(f, "=="),
 			Infix::NotEqual => write!(f, "!="),
 			Infix::LessThanEqual => write!(f, "<="),
 			Infix::LessThan => write!(f, "<"),
 			Infix::GreaterThanEqual => write!(f, ">="),
 			Infix::GreaterThan => write!(f, ">"),
+			Infix::In => write!(f, "in"),
+			Infix::NotIn => write!(f, "not in"),
+		}
+	}
+}
+
+impl std::fmt::Display for Statement {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Statement::Let(name, typer, value) => match typer {
+				Some(typer) => write!(f, "let {}: {} = {}", name, typer, value),
+				None => write!(f, "let {} = {}", name, value),
+			},
+			Statement::LetAlter(name, value) => write!(f, "{} = {}", name, value),
+			Statement::Expression(expression) | Statement::ExpressionReturn(expression) =>
+				write!(f, "{}", expression),
+			Statement::Fn { name, params, returns, body, is_async } => write!(
+				f,
+				"{}fn {}({}){} {}",
+				if *is_async { "async " } else { "" },
+				name,
+				join(params, ", "),
+				returns_to_string(returns),
+				body
+			),
+			Statement::Import(path, alias) => match alias {
+				Some(alias) => write!(f, "import {:?} as {}", path, alias),
+				None => write!(f, "import {:?}", path),
+			},
+			Statement::ImportFrom(path, names) => {
+				let names: Vec<String> = names
+					.iter()
+					.map(|(name, alias)| match alias {
+						Some(alias) => format!("{} as {}", name, alias),
+						None => name.clone(),
+					})
+					.collect();
+				write!(f, "from {:?} import {}", path, names.join(", "))
+			},
+			Statement::Export(statement) => write!(f, "export {}", statement),
+			Statement::Doc(doc, statement) => {
+				for line in doc.lines() {
+					writeln!(f, "/// {}", line)?;
+				}
+				write!(f, "{}", statement)
+			},
+			Statement::ModuleDoc(doc) => write!(
+				f,
+				"{}",
+				doc.lines().map(|line| format!("//! {}", line)).collect::<Vec<String>>().join("\n")
+			),
+			Statement::Yield(expression) => write!(f, "yield {}", expression),
+			Statement::Assert(condition, message, _) => match message {
+				Some(message) => write!(f, "assert {}, {}", condition, message),
+				None => write!(f, "assert {}", condition),
+			},
+			Statement::Defer(expression) => write!(f, "defer {}", expression),
+			Statement::With { value, name, body } =>
+				write!(f, "with {} as {} {}", value, name, body),
+			Statement::For { label, targets, iter, body } => {
+				if let Some(label) = label {
+					write!(f, "'{}: ", label)?;
+				}
+
+				if targets.len() == 1 {
+					write!(f, "for {} in {} {}", targets[0], iter, body)
+				} else {
+					write!(f, "for ({}) in {} {}", targets.join(", "), iter, body)
+				}
+			},
+			Statement::Break(label) => match label {
+				Some(label) => write!(f, "break '{}", label),
+				None => write!(f, "break"),
+			},
+			Statement::Continue(label) => match label {
+				Some(label) => write!(f, "continue '{}", label),
+				None => write!(f, "continue"),
+			},
 		}
 	}
 }
 
+impl std::fmt::Display for Block {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		if self.0.is_empty() {
+			return write!(f, "{{}}");
+		}
+
+		write!(f, "{{ {} }}", join(&self.0, "; "))
+	}
+}
+
+impl std::fmt::Display for Expression {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Expression::Identifier(identifier) => write!(f, "{}", identifier),
+			Expression::Literal(literal) => write!(f, "{}", literal),
+			Expression::Prefix(prefix, right) => match right.as_ref() {
+				Expression::Infix(..) => write!(f, "{}({})", prefix, right),
+				right => write!(f, "{}{}", prefix, right),
+			},
+			Expression::Infix(infix, left, right) => {
+				let precedence: Precedence = Precedence::from_infix(infix);
+
+				match left.as_ref() {
+					Expression::Infix(left_infix, ..)
+						if Precedence::from_infix(left_infix) < precedence =>
+						write!(f, "({})", left)?,
+					left => write!(f, "{}", left)?,
+				}
+
+				write!(f, " {} ", infix)?;
+
+				match right.as_ref() {
+					Expression::Infix(right_infix, ..)
+						if Precedence::from_infix(right_infix) <= precedence =>
+						write!(f, "({})", right),
+					right => write!(f, "{}", right),
+				}
+			},
+			Expression::Fn { params, returns, body, is_async } => write!(
+				f,
+				"{}fn ({}){} {}",
+				if *is_async { "async " } else { "" },
+				join(params, ", "),
+				returns_to_string(returns),
+				body
+			),
+			Expression::Await(expression) => write!(f, "await {}", expression),
+			Expression::Call { function, arguments } =>
+				write!(f, "{}({})", function, join(arguments, ", ")),
+			Expression::Index(left, index) => write!(f, "{}[{}]", left, index),
+			Expression::Slice(left, start, end) => {
+				let bound = |bound: &Option<Box<Expression>>| match bound {
+					Some(bound) => bound.to_string(),
+					None => String::new(),
+				};
+				write!(f, "{}[{}:{}]", left, bound(start), bound(end))
+			},
+			Expression::Property(left, property) => write!(f, "{}::{}", left, property),
+		}
+	}
+}
+
+impl std::fmt::Display for Literal {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Literal::Null => write!(f, "null"),
+			Literal::Integer(integer) => write!(f, "{}", integer),
+			Literal::Float(float) => write!(f, "{}", big_rational_to_string(float.clone())),
+			Literal::Boolean(boolean) => write!(f, "{}", boolean),
+			Literal::String(string) => write!(f, "{:?}", string),
+			Literal::Char(c) => write!(f, "{:?}", c),
+			Literal::Bytes(bytes) => write!(f, "{}", bytes_to_string(bytes)),
+			Literal::Vec(values) => write!(f, "[{}]", join(values, ", ")),
+			Literal::Tuple(values) => write!(f, "({})", join(values, ", ")),
+			Literal::HashMap(values) => {
+				let entries: Vec<String> =
+					values.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+				write!(f, "{{{}}}", entries.join(", "))
+			},
+			Literal::Set(values) => write!(f, "#{{{}}}", join(values, ", ")),
+			Literal::VecComprehension(element, clauses) =>
+				write!(f, "[{} {}]", element, join(clauses, " ")),
+			Literal::SetComprehension(element, clauses) =>
+				write!(f, "#{{{} {}}}", element, join(clauses, " ")),
+			Literal::HashMapComprehension(entry, clauses) =>
+				write!(f, "{{{}: {} {}}}", entry.0, entry.1, join(clauses, " ")),
+		}
+	}
+}
+
+impl std::fmt::Display for ComprehensionFor {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		if self.targets.len() == 1 {
+			write!(f, "for {} in {}", self.targets[0], self.iter)?;
+		} else {
+			write!(f, "for ({}) in {}", self.targets.join(", "), self.iter)?;
+		}
+
+		for condition in self.conditions.iter() {
+			write!(f, " if {}", condition)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl std::fmt::Display for Param {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match &self.typer {
+			Some(typer) => write!(f, "{}: {}", self.name, typer),
+			None => write!(f, "{}", self.name),
+		}
+	}
+}
+
+fn returns_to_string(returns: &Option<String>) -> String {
+	match returns {
+		Some(typer) => format!(" -> {}", typer),
+		None => String::new(),
+	}
+}
+
+fn join<T: std::fmt::Display>(values: &[T], separator: &str) -> String {
+	values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(separator)
+}
+
 impl AbstractSyntaxTree {
-	pub fn new() -> Self { Self { statements: Vec::new() } }
+	pub fn new() -> Self { Self { statements: Vec::new(), positions: Vec::new() } }
+
+	pub fn push(&mut self, statement: Statement) {
+		self.push_positioned(Position::default(), statement);
+	}
+
+	pub fn push_positioned(&mut self, position: Position, statement: Statement) {
+		self.statements.push(statement);
+		self.positions.push(position);
+	}
+}
+
+impl PartialEq for AbstractSyntaxTree {
+	fn eq(&self, other: &Self) -> bool { self.statements == other.statements }
+}
+
+impl Block {
+	pub fn new(statements: Vec<Statement>) -> Self { Self(statements, Vec::new()) }
+
+	/// Runs the statements in the current scope, returning the value of the
+	/// last one. A pending `break` or `continue` stops the block early.
+	pub fn eval(&self, interpreter: &mut Interpreter) -> GLResult {
+		let mut result: Object = Object::Null;
+
+		for statement in self.0.iter() {
+			result = statement.eval(interpreter)?;
+
+			if interpreter.control.is_some() {
+				break;
+			}
+		}
+
+		gl_ok!(result)
+	}
+
+	pub fn is_generator(&self) -> bool {
+		self.contains(|statement| matches!(statement, Statement::Yield(_)))
+	}
+
+	/// Whether any statement of the block, or of the `for` and `with` blocks
+	/// nested in it, matches `predicate`. Nested functions are not searched.
+	pub fn contains(&self, predicate: fn(&Statement) -> bool) -> bool {
+		self.0.iter().any(|statement| {
+			predicate(statement)
+				|| match statement {
+					Statement::For { body, .. } | Statement::With { body, .. } =>
+						body.contains(predicate),
+					_ => false,
+				}
+		})
+	}
+}
 
-	pub fn push(&mut self, statement: Statement) { self.statements.push(statement); }
+impl PartialEq for Block {
+	fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
 }
 
 impl Precedence {
 	pub fn from_token(token: &Token) -> Self { Self::from_token_type(&token.typer) }
 
+	pub fn from_infix(infix: &Infix) -> Self {
+		match infix {
+			Infix::Equal | Infix::NotEqual => Precedence::Equality,
+			Infix::LessThan
+			| Infix::LessThanEqual
+			| Infix::GreaterThan
+			| Infix::GreaterThanEqual
+			| Infix::In
+			| Infix::NotIn => Precedence::Relational,
+			Infix::BitOr => Precedence::BitOr,
+			Infix::BitXor => Precedence::BitXor,
+			Infix::BitAnd => Precedence::BitAnd,
+			Infix::Plus | Infix::Minus => Precedence::Additive,
+			Infix::Multiply | Infix::Divide => Precedence::Multiplicative,
+		}
+	}
+
 	pub fn from_token_type(token_type: &TokenType) -> Self {
 		match token_type {
 			TokenType::COMMA => Precedence::Comma,
@@ -121,7 +462,12 @@ impl Precedence {
 			TokenType::LessThan
 			| TokenType::LessThanEqual
 			| TokenType::GreaterThan
-			| TokenType::GreaterThanEqual => Precedence::Relational,
+			| TokenType::GreaterThanEqual
+			| TokenType::IN
+			| TokenType::NOT => Precedence::Relational,
+			TokenType::PIPE => Precedence::BitOr,
+			TokenType::CARET => Precedence::BitXor,
+			TokenType::AMPERSAND => Precedence::BitAnd,
 			TokenType::PLUS | TokenType::MINUS => Precedence::Additive,
 			TokenType::ASTERISK | TokenType::SLASH => Precedence::Multiplicative,
 			TokenType::LeftParen => Precedence::Call,
@@ -202,8 +548,13 @@ impl Into<Literal> for Vec<(Expression, Expression)> {
 
 impl Statement {
 	pub fn eval(&self, interpreter: &mut Interpreter) -> GLResult {
+		let result: GLResult = self.run(interpreter);
+		interpreter.close_abandoned(result)
+	}
+
+	fn run(&self, interpreter: &mut Interpreter) -> GLResult {
 		match self {
-			Statement::Let(name, value) => {
+			Statement::Let(name, typer, value) => {
 				let value_object: Object = match value.eval(interpreter) {
 					Ok(object) => object,
 					Err(exception) => {
@@ -212,6 +563,8 @@ impl Statement {
 					},
 				};
 
+				check_type(typer, &value_object, || format!("variable '{}'", name))?;
+				interpreter.scopes.stack[interpreter.current_level].declare(name, typer);
 				interpreter.set(name, value_object);
 			},
 			Statement::LetAlter(name, value) => {
@@ -232,68 +585,90 @@ impl Statement {
 					},
 				};
 
+				let typer: Option<String> =
+					interpreter.scopes.stack[interpreter.current_level].types.get(name).cloned();
+				check_type(&typer, &value_object, || format!("variable '{}'", name))?;
 				interpreter.set(name, value_object);
 			},
-			Statement::Fn { name, params, body } => {
+			Statement::Fn { name, params, returns, body, is_async } => {
 				interpreter.set(
 					name,
-					Object::Fn(GFunction::new(Some(name.clone()), params.clone(), body.clone())),
+					Object::Fn(GFunction::new(
+						Some(name.clone()),
+						params.clone(),
+						returns.clone(),
+						body.clone(),
+						*is_async,
+					)),
 				);
 			},
 			Statement::Expression(expression) => {
 				let _ = expression.eval(interpreter)?;
 			},
 			Statement::ExpressionReturn(expression) => return expression.eval(interpreter),
-			Statement::Import(path_string) => {
-				let path = std::path::Path::new(&path_string);
-				use libloading::Library;
-
-				if path.is_file() && path.extension().unwrap() == "gl" {
-					let _ = interpreter.script(path.to_str().unwrap());
-					interpreter.set(
-						format!("{}", path.file_stem().unwrap().to_str().unwrap()),
-						Object::Module(GModule::new(
-							format!("{}", path.file_stem().unwrap().to_str().unwrap()),
-							path_string.clone(),
-							interpreter
-								.scopes
-								.stack
-								.get(interpreter.current_level + 1)
-								.unwrap()
-								.clone(),
-						)),
-					);
-				} else {
-					let name: String = format!("{}", path.file_stem().unwrap().to_str().unwrap());
-					let dynlibrary: Library = unsafe {
-						match Library::new(path) {
-							Ok(dynlibrary) => dynlibrary,
-							Err(err) => {
-								let mut exception: Exception =
-									Exception::in_runtime(Except::error(err.to_string()));
-								exception.push(ExceptionPoint::new(
-									"&self.module_context",
-									Position::default(),
-								));
-								return Err(exception);
-							},
-						}
-					};
+			Statement::Yield(_) => {
+				let mut exception: Exception =
+					Exception::in_runtime(Except::invalid_syntax("'yield' outside function"));
+				exception
+					.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+				return Err(exception);
+			},
+			Statement::Assert(condition, message, source) =>
+				assert(interpreter, condition, message, source)?,
+			Statement::Defer(expression) => interpreter.defer(expression.clone()),
+			Statement::With { value, name, body } => return with(interpreter, value, name, body),
+			Statement::For { label, targets, iter, body } =>
+				for_loop(interpreter, label, targets, iter, body)?,
+			Statement::Break(label) => interpreter.control = Some(Control::Break(label.clone())),
+			Statement::Continue(label) =>
+				interpreter.control = Some(Control::Continue(label.clone())),
+			Statement::Export(statement) => {
+				statement.eval(interpreter)?;
+
+				if let Statement::Let(name, ..) | Statement::Fn { name, .. } = statement.as_ref() {
+					interpreter.export(name);
+				}
+			},
+			Statement::Doc(doc, statement) => {
+				statement.eval(interpreter)?;
+
+				let mut documented: &Statement = statement;
+				while let Statement::Export(statement) = documented {
+					documented = statement;
+				}
 
-					let moduledynlibrary: ModuleDynLibrary = ModuleDynLibrary::new(
-						&name,
-						&path_string,
-						Rc::new(RefCell::new(dynlibrary)),
-						Rc::new(RefCell::new(Scope::new())),
-					);
-
-					if let Ok(Object::FnNative(GFunctionNative { name: _, params_len: _, body })) =
-						moduledynlibrary.get_attr("gl_plugin_init")
-					{
-						body(interpreter, Vec::new())?;
+				if let Statement::Let(name, ..) | Statement::Fn { name, .. } = documented {
+					interpreter.document(name, doc);
+				}
+			},
+			Statement::ModuleDoc(doc) => interpreter.document_module(doc),
+			Statement::Import(path, alias) => {
+				let module: Object = interpreter.import_module(path)?;
+				let name: String = match alias {
+					Some(alias) => alias.clone(),
+					None => module_name(&module),
+				};
+				interpreter.set(name, module);
+			},
+			Statement::ImportFrom(path, names) => {
+				let module: Object = interpreter.import_module(path)?;
+
+				for (name, alias) in names {
+					let member: Object = match module_member(&module, name) {
+						Some(member) => member,
+						None => {
+							let mut exception: Exception = Exception::in_runtime(Except::import(
+								format!("cannot import name '{}' from '{}'", name, path),
+							));
+							exception.push(ExceptionPoint::new(
+								"self.module_context.clone()",
+								Position::default(),
+							));
+							return Err(exception);
+						},
 					};
 
-					interpreter.set(name, Object::ModuleDynLibrary(moduledynlibrary));
+					interpreter.set(alias.as_ref().unwrap_or(name), member);
 				}
 			},
 		}
@@ -325,6 +700,8 @@ impl Expression {
 				Literal::Float(float) => Object::Float(float),
 				Literal::Boolean(boolean) => Object::Boolean(boolean),
 				Literal::String(string) => Object::String(string),
+				Literal::Char(c) => Object::Char(c),
+				Literal::Bytes(bytes) => Object::Bytes(bytes),
 				Literal::Vec(vector_literal) => {
 					let mut vector: Vec<Object> = Vec::new();
 
@@ -354,20 +731,78 @@ impl Expression {
 
 					Ok(Object::HashMap(hashmap))
 				}?,
+				Literal::Set(set_literal) => {
+					let mut set: HashSet<Object> = HashSet::new();
+
+					for expression in set_literal {
+						set.insert(expression.eval(interpreter)?);
+					}
+
+					Ok(Object::Set(set))
+				}?,
+				Literal::VecComprehension(element, clauses) => {
+					let mut vector: Vec<Object> = Vec::new();
+					comprehension(interpreter, &clauses, &mut |interpreter| {
+						vector.push(element.eval(interpreter)?);
+						Ok(())
+					})?;
+					Object::Vec(vector)
+				},
+				Literal::SetComprehension(element, clauses) => {
+					let mut set: HashSet<Object> = HashSet::new();
+					comprehension(interpreter, &clauses, &mut |interpreter| {
+						set.insert(element.eval(interpreter)?);
+						Ok(())
+					})?;
+					Object::Set(set)
+				},
+				Literal::HashMapComprehension(entry, clauses) => {
+					let mut hashmap: HashMap<Object, Object> = HashMap::new();
+					comprehension(interpreter, &clauses, &mut |interpreter| {
+						let key: Object = entry.0.eval(interpreter)?;
+						let value: Object = entry.1.eval(interpreter)?;
+						hashmap.insert(key, value);
+						Ok(())
+					})?;
+					Object::HashMap(hashmap)
+				},
 			},
 			Expression::Prefix(prefix, right_expression) =>
 				self.prefix(prefix.clone(), right_expression.eval(interpreter)?)?,
-			Expression::Infix(infix, left_expression, right_expression) => self.infix(
-				infix.clone(),
-				left_expression.eval(interpreter)?,
-				right_expression.eval(interpreter)?,
-			)?,
-			Expression::Fn { params, body } =>
-				Object::Fn(GFunction::new(None, params.clone(), body.clone())),
+			Expression::Infix(infix, left_expression, right_expression) => {
+				let left: Object = left_expression.eval(interpreter)?;
+				let right: Object = right_expression.eval(interpreter)?;
+				self.infix_object(interpreter, infix, left, right)?
+			},
+			Expression::Fn { params, returns, body, is_async } => Object::Fn(GFunction::new(
+				None,
+				params.clone(),
+				returns.clone(),
+				body.clone(),
+				*is_async,
+			)),
+			// An async body pauses at its awaits before they get here; anywhere
+			// else `await` blocks until the future is done.
+			Expression::Await(expression) => {
+				let future: Object = expression.eval(interpreter)?;
+				interpreter.run_until_complete(future)?
+			},
 			Expression::Call { function, arguments } =>
 				self.call(interpreter, function.clone(), arguments.clone())?,
 			Expression::Index(left_expression, index_expression) =>
 				self.index(left_expression.eval(interpreter)?, index_expression.eval(interpreter)?)?,
+			Expression::Slice(left_expression, start, end) => {
+				let left: Object = left_expression.eval(interpreter)?;
+				let start: Option<Object> = match start {
+					Some(start) => Some(start.eval(interpreter)?),
+					None => None,
+				};
+				let end: Option<Object> = match end {
+					Some(end) => Some(end.eval(interpreter)?),
+					None => None,
+				};
+				slice(left, start, end)?
+			},
 			Expression::Property(p1, p2) =>
 				self.property(interpreter, *p1.clone(), *p2.clone()).0?,
 		};
@@ -375,6 +810,39 @@ impl Expression {
 		gl_ok!(result)
 	}
 
+	/// Whether evaluating the expression may run an `await`. Bodies of
+	/// nested functions are not searched.
+	pub fn contains_await(&self) -> bool {
+		match self {
+			Expression::Await(_) => true,
+			Expression::Identifier(_) | Expression::Fn { .. } => false,
+			Expression::Prefix(_, right) => right.contains_await(),
+			Expression::Infix(_, left, right)
+			| Expression::Index(left, right)
+			| Expression::Property(left, right) => left.contains_await() || right.contains_await(),
+			Expression::Call { function, arguments } =>
+				function.contains_await() || arguments.iter().any(Expression::contains_await),
+			Expression::Slice(left, start, end) =>
+				left.contains_await()
+					|| start.iter().chain(end.iter()).any(|bound| bound.contains_await()),
+			Expression::Literal(literal) => match literal {
+				Literal::Vec(values) | Literal::Tuple(values) | Literal::Set(values) =>
+					values.iter().any(Expression::contains_await),
+				Literal::HashMap(entries) => entries
+					.iter()
+					.any(|(key, value)| key.contains_await() || value.contains_await()),
+				Literal::VecComprehension(element, clauses)
+				| Literal::SetComprehension(element, clauses) =>
+					element.contains_await() || clauses.iter().any(ComprehensionFor::contains_await),
+				Literal::HashMapComprehension(entry, clauses) =>
+					entry.0.contains_await()
+						|| entry.1.contains_await()
+						|| clauses.iter().any(ComprehensionFor::contains_await),
+				_ => false,
+			},
+		}
+	}
+
 	pub fn prefix(&self, prefix: Prefix, right: Object) -> GLResult {
 		match prefix {
 			Prefix::Not => self.prefix_not_op(right),
@@ -384,12 +852,7 @@ impl Expression {
 	}
 
 	pub fn prefix_not_op(&self, right: Object) -> GLResult {
-		Ok(match right {
-			Object::Null => Object::Boolean(true),
-			Object::Boolean(true) => Object::Boolean(false),
-			Object::Boolean(false) => Object::Boolean(true),
-			_ => Object::Boolean(false),
-		})
+		Ok(Object::Boolean(!right.is_truthy()))
 	}
 
 	pub fn prefix_plus_op(&self, right: Object) -> GLResult {
@@ -435,6 +898,32 @@ impl Expression {
 	pub fn index(&self, left: Object, index: Object) -> GLResult {
 		match (left, index) {
 			(Object::Vec(vector), Object::Integer(integer)) => self.index_vec(vector, integer),
+			(Object::Bytes(bytes), Object::Integer(integer)) =>
+				match integer.to_usize().and_then(|index| bytes.get(index)) {
+					Some(byte) => Ok(Object::Integer(byte.to_bigint().unwrap())),
+					None => {
+						let mut exception: Exception =
+							Exception::in_runtime(Except::index("bytes index out of range"));
+						exception.push(ExceptionPoint::new(
+							"self.module_context.clone(),",
+							Position::default(),
+						));
+						return Err(exception);
+					},
+				},
+			(Object::String(string), Object::Integer(integer)) =>
+				match integer.to_usize().and_then(|index| string.chars().nth(index)) {
+					Some(c) => Ok(Object::Char(c)),
+					None => {
+						let mut exception: Exception =
+							Exception::in_runtime(Except::index("string index out of range"));
+						exception.push(ExceptionPoint::new(
+							"self.module_context.clone(),",
+							Position::default(),
+						));
+						return Err(exception);
+					},
+				},
 			(Object::Vec(_), index) => {
 				let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
 					"list indices must be integers, not {}",
@@ -489,6 +978,20 @@ impl Expression {
 		}
 	}
 
+	/// Like `infix`, but membership tests against a generator consume it
+	/// through the interpreter.
+	pub fn infix_object(
+		&self, interpreter: &mut Interpreter, infix: &Infix, left: Object, right: Object,
+	) -> GLResult {
+		match (infix, right) {
+			(Infix::In, Object::Generator(generator)) =>
+				Ok(Object::Boolean(generator.contains(interpreter, &left)?)),
+			(Infix::NotIn, Object::Generator(generator)) =>
+				Ok(Object::Boolean(!generator.contains(interpreter, &left)?)),
+			(infix, right) => self.infix(infix.clone(), left, right),
+		}
+	}
+
 	pub fn infix(&self, infix: Infix, left: Object, right: Object) -> GLResult {
 		match {
 			match infix {
@@ -496,12 +999,17 @@ impl Expression {
 				Infix::Minus => left - right,
 				Infix::Multiply => left * right,
 				Infix::Divide => left / right,
+				Infix::BitOr => left | right,
+				Infix::BitAnd => left & right,
+				Infix::BitXor => left ^ right,
 				Infix::Equal => Ok(Object::Boolean(left == right)),
 				Infix::NotEqual => Ok(Object::Boolean(left != right)),
 				Infix::LessThan => Ok(Object::Boolean(left < right)),
 				Infix::LessThanEqual => Ok(Object::Boolean(left <= right)),
 				Infix::GreaterThan => Ok(Object::Boolean(left > right)),
 				Infix::GreaterThanEqual => Ok(Object::Boolean(left >= right)),
+				Infix::In => right.contains(&left).map(Object::Boolean),
+				Infix::NotIn => right.contains(&left).map(|b| Object::Boolean(!b)),
 			}
 		} {
 			Ok(r) => Ok(r),
@@ -523,13 +1031,19 @@ impl Expression {
 	pub fn call_object(
 		&self, interpreter: &mut Interpreter, function: Object, arguments: Vec<Expression>,
 	) -> GLResult {
-		let mut result: Object = Object::Null;
 		let mut args: Vec<Object> = Vec::new();
 		for arg in arguments {
 			args.push(arg.eval(interpreter)?);
 		}
 
-		let (params, body) = match function {
+		self.call_with_values(interpreter, function, args)
+	}
+
+	/// Like `call_object`, with the arguments already evaluated.
+	pub(crate) fn call_with_values(
+		&self, interpreter: &mut Interpreter, function: Object, args: Vec<Object>,
+	) -> GLResult {
+		let (name, params, returns, body, is_async) = match function {
 			Object::FnNative(GFunctionNative { name, params_len, body }) =>
 				return if params_len < 0 || params_len == args.len() as i32 {
 					match body(interpreter, args) {
@@ -554,9 +1068,9 @@ impl Expression {
 					)));
 					Err(exception)
 				},
-			Object::Fn(GFunction { name, params, body }) =>
+			Object::Fn(GFunction { name, params, returns, body, is_async, .. }) =>
 				if params.len() == args.len() {
-					(params, body)
+					(name, params, returns, body, is_async)
 				} else {
 					let exception: Exception = Exception::in_runtime(Except::type_(format!(
 						"{}() expected {} argument, found {}",
@@ -580,23 +1094,33 @@ impl Expression {
 			},
 		};
 
-		// let mut new_scoped: Env = Env::from_parent(Rc::clone(&self.env));
-		// for (name, o) in params.iter().zip(args) {
-		// 	new_scoped.set(name, o);
-		// }
-
-		interpreter.scopes.create_scope();
-		interpreter.current_level += 1;
+		let name_fn: String = match &name {
+			Some(name_fn) => name_fn.clone(),
+			None => String::from("<anonymous>"),
+		};
 
-		for (name, o) in params.iter().zip(args) {
-			interpreter.set(name, o);
+		let mut scope: Scope = Scope::new();
+		for (param, o) in params.iter().zip(args) {
+			check_type(&param.typer, &o, || format!("argument '{}' of {}()", param.name, name_fn))?;
+			scope.declare(&param.name, &param.typer);
+			scope.set(&param.name, o);
 		}
 
-		for statement in body.0 {
-			result = statement.eval(interpreter)?;
+		if is_async {
+			return gl_ok!(GFuture::coroutine(interpreter, name, returns, body, scope));
+		} else if body.is_generator() {
+			return gl_ok!(GGenerator::new(interpreter, name, body, scope));
 		}
 
-		interpreter.current_level -= 1;
+		interpreter.push_scope(scope);
+
+		let result: GLResult = body.eval(interpreter);
+		let result: GLResult = interpreter.run_defers(result);
+
+		interpreter.pop_scope();
+
+		let result: Object = interpreter.close_abandoned(result)?;
+		check_type(&returns, &result, || format!("return value of {}()", name_fn))?;
 
 		gl_ok!(result)
 	}
@@ -688,34 +1212,12 @@ impl Expression {
 		&self, interpreter: &mut Interpreter, gmn: GModule, property: Expression,
 	) -> (GLResult, String) {
 		if let Expression::Identifier(identifier) = property {
-			match gmn.get(identifier.clone()) {
-				Some(o) => (Ok(o.clone()), identifier.clone()),
-				None => {
-					let mut exception: Exception = Exception::in_runtime(Except::attribute(
-						format!("module '{}' has no attribute '{}'", gmn.name, identifier),
-					));
-					exception.push(ExceptionPoint::new(
-						"self.module_context.clone()",
-						Position::default(),
-					));
-					(Err(exception), format!(""))
-				},
-			}
+			(self.module_attribute(&gmn, &identifier), identifier.clone())
 		} else if let Expression::Call { function, arguments } = property {
 			if let Expression::Identifier(identifier) = *function {
-				match gmn.get(identifier.clone()) {
-					Some(o) =>
-						(self.call_object(interpreter, o.clone(), arguments), identifier.clone()),
-					None => {
-						let mut exception: Exception = Exception::in_runtime(Except::attribute(
-							format!("module '{}' has no attribute '{}'", gmn.name, identifier),
-						));
-						exception.push(ExceptionPoint::new(
-							"self.module_context.clone()",
-							Position::default(),
-						));
-						(Err(exception), format!(""))
-					},
+				match self.module_attribute(&gmn, &identifier) {
+					Ok(o) => (self.call_object(interpreter, o, arguments), identifier.clone()),
+					e => (e, format!("")),
 				}
 			} else {
 				(self.invalid_syntax(), format!(""))
@@ -730,6 +1232,18 @@ impl Expression {
 		}
 	}
 
+	fn module_attribute(&self, gmn: &GModule, identifier: &str) -> GLResult {
+		let message: String = match gmn.get(identifier.to_string()) {
+			Some(o) if gmn.is_public(identifier) => return Ok(o),
+			Some(_) => format!("'{}' is private to module '{}'", identifier, gmn.name),
+			None => format!("module '{}' has no attribute '{}'", gmn.name, identifier),
+		};
+
+		let mut exception: Exception = Exception::in_runtime(Except::attribute(message));
+		exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+		Err(exception)
+	}
+
 	fn property_from_modulerust(
 		&self, interpreter: &mut Interpreter, gmn: GModuleNative, property: Expression,
 	) -> (GLResult, String) {
@@ -869,3 +1383,377 @@ impl Expression {
 		}
 	}
 }
+
+// Types
+
+/// Takes `left[start:end]` from a Vec, String or Bytes. Missing bounds
+/// default to the ends, and bounds past the end are clamped.
+fn slice(left: Object, start: Option<Object>, end: Option<Object>) -> GLResult {
+	let len: usize = match &left {
+		Object::Vec(vector) => vector.len(),
+		Object::String(string) => string.chars().count(),
+		Object::Bytes(bytes) => bytes.len(),
+		o => {
+			let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+				"'{}' object is not sliceable",
+				o.typer()
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			return Err(exception);
+		},
+	};
+
+	let bound = |bound: Option<Object>, default: usize| match bound {
+		None => Ok(default),
+		Some(Object::Integer(integer)) if integer >= 0.to_bigint().unwrap() =>
+			Ok(integer.to_usize().unwrap_or(usize::MAX).min(len)),
+		Some(o) => {
+			let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+				"slice indices must be non-negative Integers, not {}",
+				o
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			Err(exception)
+		},
+	};
+	let end: usize = bound(end, len)?;
+	let start: usize = bound(start, 0)?.min(end);
+
+	gl_ok!(match left {
+		Object::Vec(vector) => Object::Vec(vector[start..end].to_vec()),
+		Object::String(string) =>
+			Object::String(string.chars().skip(start).take(end - start).collect()),
+		Object::Bytes(bytes) => Object::Bytes(bytes[start..end].to_vec()),
+		_ => unreachable!(),
+	})
+}
+
+/// Checks `object` against an optional type annotation. `subject` names what
+/// is being checked in the error message.
+pub(crate) fn check_type(
+	typer: &Option<String>, object: &Object, subject: impl FnOnce() -> String,
+) -> Result<(), Exception> {
+	match typer {
+		Some(typer) if typer != object.typer() => {
+			let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+				"{} must be {}, not {}",
+				subject(),
+				typer,
+				object.typer()
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			Err(exception)
+		},
+		_ => Ok(()),
+	}
+}
+
+// Comprehension
+
+/// Runs `emit` once for every combination of values produced by `clauses`,
+/// inside a scope of its own so the targets never leak into the caller.
+fn comprehension(
+	interpreter: &mut Interpreter, clauses: &[ComprehensionFor],
+	emit: &mut dyn FnMut(&mut Interpreter) -> Result<(), Exception>,
+) -> Result<(), Exception> {
+	interpreter.push_scope(Scope::new());
+	let result: Result<(), Exception> = comprehension_clauses(interpreter, clauses, emit);
+	interpreter.pop_scope();
+	result
+}
+
+fn comprehension_clauses(
+	interpreter: &mut Interpreter, clauses: &[ComprehensionFor],
+	emit: &mut dyn FnMut(&mut Interpreter) -> Result<(), Exception>,
+) -> Result<(), Exception> {
+	let (clause, rest) = match clauses.split_first() {
+		Some(split) => split,
+		None => return emit(interpreter),
+	};
+
+	let mut iterator: GIterator = GIterator::new(&clause.iter.eval(interpreter)?)?;
+
+	'values: while let Some(value) = iterator.next(interpreter)? {
+		clause.bind(interpreter, value)?;
+
+		for condition in clause.conditions.iter() {
+			if !condition.eval(interpreter)?.is_truthy() {
+				continue 'values;
+			}
+		}
+
+		comprehension_clauses(interpreter, rest, emit)?;
+	}
+
+	Ok(())
+}
+
+impl ComprehensionFor {
+	pub fn contains_await(&self) -> bool {
+		self.iter.contains_await() || self.conditions.iter().any(Expression::contains_await)
+	}
+
+	fn bind(&self, interpreter: &mut Interpreter, value: Object) -> Result<(), Exception> {
+		bind_targets(interpreter, &self.targets, value)
+	}
+}
+
+/// Binds `value` to a single target, or unpacks a tuple or vec of matching
+/// length over several.
+pub(crate) fn bind_targets(
+	interpreter: &mut Interpreter, targets: &[String], value: Object,
+) -> Result<(), Exception> {
+	if targets.len() == 1 {
+		interpreter.set(&targets[0], value);
+		return Ok(());
+	}
+
+	let values: Vec<Object> = match value {
+		Object::Tuple(values) | Object::Vec(values) if values.len() == targets.len() => values,
+		o => {
+			let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+				"cannot unpack '{}' into {} values",
+				o.typer(),
+				targets.len()
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			return Err(exception);
+		},
+	};
+
+	for (target, value) in targets.iter().zip(values) {
+		interpreter.set(target, value);
+	}
+
+	Ok(())
+}
+
+// For
+
+fn for_loop(
+	interpreter: &mut Interpreter, label: &Option<String>, targets: &[String], iter: &Expression,
+	body: &Block,
+) -> Result<(), Exception> {
+	let mut iterator: GIterator = GIterator::new(&iter.eval(interpreter)?)?;
+
+	while let Some(value) = iterator.next(interpreter)? {
+		bind_targets(interpreter, targets, value)?;
+
+		// Each pass defers to its own end.
+		let defers: Vec<Expression> = interpreter.begin_defers();
+		let result: GLResult = body.eval(interpreter);
+		interpreter.end_defers(defers, result)?;
+
+		match interpreter.control.take() {
+			None => {},
+			Some(Control::Continue(target)) if target.is_none() || &target == label => {},
+			Some(Control::Break(target)) if target.is_none() || &target == label => break,
+			control => {
+				interpreter.control = control;
+				break;
+			},
+		}
+	}
+
+	Ok(())
+}
+
+// Assert
+
+fn assert(
+	interpreter: &mut Interpreter, condition: &Expression, message: &Option<Expression>,
+	source: &str,
+) -> Result<(), Exception> {
+	let mut operands: Vec<Object> = Vec::new();
+
+	let passed: bool = match condition {
+		Expression::Infix(
+			infix @ (Infix::Equal
+			| Infix::NotEqual
+			| Infix::LessThan
+			| Infix::LessThanEqual
+			| Infix::GreaterThan
+			| Infix::GreaterThanEqual
+			| Infix::In
+			| Infix::NotIn),
+			left_expression,
+			right_expression,
+		) => {
+			let left: Object = left_expression.eval(interpreter)?;
+			let right: Object = right_expression.eval(interpreter)?;
+			operands.push(left.clone());
+			operands.push(right.clone());
+			condition.infix_object(interpreter, infix, left, right)?.is_truthy()
+		},
+		condition => condition.eval(interpreter)?.is_truthy(),
+	};
+
+	if passed {
+		return Ok(());
+	}
+
+	// Conditions built by hand have no source text to show.
+	let (source, sources): (String, Vec<String>) = match source.is_empty() {
+		false => (source.to_string(), split_comparison(source)),
+		true => match condition {
+			Expression::Infix(_, left, right) if !operands.is_empty() =>
+				(condition.to_string(), vec![left.to_string(), right.to_string()]),
+			condition => (condition.to_string(), Vec::new()),
+		},
+	};
+
+	let mut report: String = match message {
+		Some(message) => match message.eval(interpreter)? {
+			Object::String(message) => format!("{}\n  assert {}", message, source),
+			message => format!("{}\n  assert {}", message, source),
+		},
+		None => format!("assert {}", source),
+	};
+
+	let mut first: bool = true;
+	for (source, value) in sources.iter().zip(operands) {
+		let value: String = value.to_string();
+
+		if source != &value {
+			report.push_str(&format!(
+				"\n  {} {} = {}",
+				if first { "where" } else { "  and" },
+				source,
+				value
+			));
+			first = false;
+		}
+	}
+
+	let mut exception: Exception = Exception::in_runtime(Except::assertion(report));
+	exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+	Err(exception)
+}
+
+/// The source texts of the two operands of a comparison: the parts around
+/// its last comparison operator outside brackets, `==` and `!=` binding
+/// looser than the others. Empty when the text holds no such operator.
+fn split_comparison(source: &str) -> Vec<String> {
+	let tokens: Vec<Token> = match Lexer::new_lossless(Source::from_string(source), "").run() {
+		Ok(tokens) => tokens,
+		Err(_) => return Vec::new(),
+	};
+
+	let mut depth: usize = 0;
+	let mut equality: Option<(usize, usize)> = None;
+	let mut relational: Option<(usize, usize)> = None;
+
+	for (index, token) in tokens.iter().enumerate() {
+		match &token.typer {
+			TokenType::LeftParen
+			| TokenType::LeftBracket
+			| TokenType::LeftBrace
+			| TokenType::HashBrace => depth += 1,
+			TokenType::RightParen | TokenType::RightBracket | TokenType::RightBrace =>
+				depth = depth.saturating_sub(1),
+			_ if depth > 0 => {},
+			TokenType::EQUAL | TokenType::NotEqual => equality = Some((index, index + 1)),
+			TokenType::LessThan
+			| TokenType::LessThanEqual
+			| TokenType::GreaterThan
+			| TokenType::GreaterThanEqual => relational = Some((index, index + 1)),
+			TokenType::IN => match index.checked_sub(1).map(|before| &tokens[before].typer) {
+				Some(TokenType::NOT) => relational = Some((index - 1, index + 1)),
+				_ => relational = Some((index, index + 1)),
+			},
+			_ => {},
+		}
+	}
+
+	let text = |tokens: &[Token]| -> String {
+		tokens.iter().map(Token::to_source).collect::<String>().trim().to_string()
+	};
+
+	match equality.or(relational) {
+		Some((operator, after)) => vec![text(&tokens[..operator]), text(&tokens[after..])],
+		None => Vec::new(),
+	}
+}
+
+// With
+
+fn with(interpreter: &mut Interpreter, value: &Expression, name: &str, body: &Block) -> GLResult {
+	let object: Object = enter_with(interpreter, value, name)?;
+	let result: GLResult = body.eval(interpreter);
+	exit_with(interpreter, value, &object, result)
+}
+
+/// Enters the object `value` evaluates to and pushes the scope of the block,
+/// with `name` bound to what `enter` returned. Returns the entered object.
+pub(crate) fn enter_with(
+	interpreter: &mut Interpreter, value: &Expression, name: &str,
+) -> Result<Object, Exception> {
+	let object: Object = value.eval(interpreter)?;
+
+	let entered: Object = match &object {
+		Object::StructRust(structrust) => match structrust.o.enter() {
+			Some(entered) => entered?,
+			None => object.clone(),
+		},
+		Object::Module(_) | Object::ModuleNative(_) | Object::HashMap(_) =>
+			match with_hook(&object, "enter") {
+				Some(function) => value.call_with_values(interpreter, function, Vec::new())?,
+				None => object.clone(),
+			},
+		o => {
+			let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+				"'{}' object can't be used in 'with' statement",
+				o.typer()
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			return Err(exception);
+		},
+	};
+
+	let mut scope: Scope = Scope::new();
+	scope.set(name, entered);
+	interpreter.push_scope(scope);
+
+	Ok(object)
+}
+
+/// Leaves the block entered by `enter_with`: runs its defers, pops its scope
+/// and exits `object`. An exception from the block wins over one from exit.
+pub(crate) fn exit_with(
+	interpreter: &mut Interpreter, value: &Expression, object: &Object, result: GLResult,
+) -> GLResult {
+	let result: GLResult = interpreter.run_defers(result);
+
+	interpreter.pop_scope();
+
+	let exited: Result<(), Exception> = match object {
+		Object::StructRust(structrust) => structrust.o.exit(result.as_ref().err()),
+		object => match with_hook(object, "exit") {
+			// An `exit` taking a parameter gets the exception leaving the
+			// block, as `"Type: message"`, or null.
+			Some(function) => {
+				let args: Vec<Object> = match (&function, result.as_ref()) {
+					(Object::Fn(GFunction { params, .. }), _) if params.is_empty() => Vec::new(),
+					(Object::FnNative(GFunctionNative { params_len: 0, .. }), _) => Vec::new(),
+					(_, Ok(_)) => vec![Object::Null],
+					(_, Err(exception)) => vec![Object::String(exception.except.to_string())],
+				};
+				value.call_with_values(interpreter, function, args).map(|_| ())
+			},
+			None => Ok(()),
+		},
+	};
+
+	result.and_then(|object| exited.map(|_| object))
+}
+
+/// The `enter` or `exit` hook of a module or of a hashmap holding one under
+/// that key.
+fn with_hook(object: &Object, name: &str) -> Option<Object> {
+	match object {
+		Object::Module(module) => module.get(String::from(name)),
+		Object::ModuleNative(module) => module.context.get(name).cloned(),
+		Object::HashMap(hashmap) => hashmap.get(&Object::String(String::from(name))).cloned(),
+		_ => None,
+	}
+}
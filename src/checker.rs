@@ -0,0 +1,583 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+/// The type of an expression as far as the checker can tell, named like
+/// `Object::typer`. `Dynamic` stands for anything only known at runtime and
+/// never causes an error.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+	Dynamic,
+	Named(String),
+}
+
+/// Finds type errors in a program without running it. Types come from
+/// literals, operators (following the rules in `object/ops.rs`), function
+/// returns and annotations. A name is only trusted when it is bound once in
+/// its scope, so dynamically typed programs are never rejected.
+pub struct Checker {
+	module: String,
+	scopes: Vec<CheckScope>,
+	position: Position,
+	errors: Vec<Exception>,
+}
+
+struct CheckScope {
+	counts: HashMap<String, usize>,
+	bindings: HashMap<String, Binding>,
+	/// Function bodies do not see the names around them: with dynamic
+	/// scoping those belong to whoever calls the function.
+	inherits: bool,
+}
+
+#[derive(Clone)]
+enum Binding {
+	Value(Type),
+	Fn(Signature),
+}
+
+#[derive(Clone)]
+struct Signature {
+	name: String,
+	params: Vec<Param>,
+	returns: Type,
+}
+
+const NUMBERS: [&str; 3] = ["Integer", "Float", "Boolean"];
+const ITERABLES: [&str; 7] = ["Vec", "Tuple", "Set", "HashMap", "String", "Bytes", "Generator"];
+
+impl Type {
+	fn named<T: Into<String>>(name: T) -> Self { Type::Named(name.into()) }
+
+	fn is(&self, name: &str) -> bool { matches!(self, Type::Named(typer) if typer == name) }
+
+	fn is_any(&self, names: &[&str]) -> bool { names.iter().any(|name| self.is(name)) }
+}
+
+impl std::fmt::Display for Type {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Type::Dynamic => write!(f, "Dynamic"),
+			Type::Named(typer) => write!(f, "{}", typer),
+		}
+	}
+}
+
+impl Checker {
+	pub fn new<T: Into<String>>(module: T) -> Self {
+		Self {
+			module: module.into(),
+			scopes: Vec::new(),
+			position: Position::default(),
+			errors: Vec::new(),
+		}
+	}
+
+	/// Checks a parsed program. Errors point at the statement they were
+	/// found in, or at the start of the module for statements built without
+	/// positions.
+	pub fn check(&mut self, ast: &AbstractSyntaxTree) -> Vec<Exception> {
+		let body: Block = Block(ast.statements.clone(), ast.positions.clone());
+
+		self.errors = Vec::new();
+		self.position = Position::default();
+		self.scopes = vec![CheckScope::new(&body.0, &[], false)];
+		self.block(&body);
+		self.scopes.clear();
+
+		std::mem::take(&mut self.errors)
+	}
+
+	/// Parses and checks a program. A syntax error is returned as is.
+	pub fn check_source(&mut self, source: Source) -> Result<Vec<Exception>, Exception> {
+		let lexer: Lexer = Lexer::new(source, &self.module);
+		let ast: AbstractSyntaxTree = Parser::new(lexer)?.run()?;
+		Ok(self.check(&ast))
+	}
+
+	fn error<T: Into<String>>(&mut self, message: T) {
+		let mut exception: Exception = Exception::not_runtime(Except::type_(message));
+		exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+		self.errors.push(exception);
+	}
+
+	// Scopes
+
+	fn bind(&mut self, name: &str, binding: Binding) {
+		let scope: &mut CheckScope = self.scopes.last_mut().unwrap();
+
+		if scope.counts.get(name) == Some(&1) {
+			scope.bindings.insert(name.to_string(), binding);
+		} else {
+			scope.bindings.insert(name.to_string(), Binding::Value(Type::Dynamic));
+		}
+	}
+
+	fn lookup(&self, name: &str) -> Option<Binding> {
+		for scope in self.scopes.iter().rev() {
+			if let Some(binding) = scope.bindings.get(name) {
+				return Some(binding.clone());
+			} else if scope.counts.contains_key(name) || !scope.inherits {
+				return None;
+			}
+		}
+
+		None
+	}
+
+	fn lookup_type(&self, name: &str) -> Type {
+		match self.lookup(name) {
+			Some(Binding::Value(typer)) => typer,
+			Some(Binding::Fn(_)) => Type::named("Fn"),
+			None => Type::Dynamic,
+		}
+	}
+
+	// Statements
+
+	/// Checks a block and returns the type of the value it evaluates to.
+	/// Checks the statements of a block, each at its own position. The
+	/// position in effect before is restored afterwards.
+	fn block(&mut self, block: &Block) -> Type {
+		let position: Position = self.position.copy();
+		let mut result: Type = Type::named("Null");
+
+		for (index, statement) in block.0.iter().enumerate() {
+			if let Some(start) = block.1.get(index) {
+				self.position = start.copy();
+			}
+			result = self.statement(statement);
+		}
+
+		self.position = position;
+		result
+	}
+
+	fn statement(&mut self, statement: &Statement) -> Type {
+		match statement {
+			Statement::Let(name, typer, value) => {
+				let value: Type = self.expression(value);
+
+				match typer {
+					Some(typer) => {
+						if let Type::Named(value) = &value {
+							if value != typer {
+								self.error(format!(
+									"variable '{}' must be {}, not {}",
+									name, typer, value
+								));
+							}
+						}
+
+						self.bind(name, Binding::Value(Type::named(typer)));
+					},
+					None => self.bind(name, Binding::Value(value)),
+				}
+			},
+			Statement::LetAlter(name, value) => {
+				let _ = self.expression(value);
+				self.bind(name, Binding::Value(Type::Dynamic));
+			},
+			Statement::Expression(expression) => {
+				let _ = self.expression(expression);
+			},
+			Statement::ExpressionReturn(expression) => return self.expression(expression),
+			Statement::Fn { name, params, returns, body, is_async } => {
+				let signature: Signature = self.function(name, params, returns, body, *is_async);
+				self.bind(name, Binding::Fn(signature));
+			},
+			Statement::Import(path, alias) => {
+				let name: String = import_name(path, alias);
+				self.bind(&name, Binding::Value(Type::Dynamic));
+			},
+			Statement::ImportFrom(_, names) =>
+				for (name, alias) in names {
+					self.bind(alias.as_ref().unwrap_or(name), Binding::Value(Type::Dynamic));
+				},
+			Statement::Yield(expression) | Statement::Defer(expression) => {
+				let _ = self.expression(expression);
+			},
+			Statement::Assert(condition, message, _) => {
+				let _ = self.expression(condition);
+
+				if let Some(message) = message {
+					let _ = self.expression(message);
+				}
+			},
+			Statement::With { value, name, body } => {
+				let value: Type = self.expression(value);
+
+				if let Type::Named(typer) = &value {
+					if !value.is_any(&["ObjectRust", "Module", "HashMap"]) {
+						self.error(format!("'{}' object can't be used in 'with' statement", typer));
+					}
+				}
+
+				let params: Vec<Param> = vec![Param { name: name.clone(), typer: None }];
+				self.scopes.push(CheckScope::new(&body.0, &params, true));
+				self.bind(name, Binding::Value(Type::Dynamic));
+				let result: Type = self.block(body);
+				self.scopes.pop();
+				return result;
+			},
+			Statement::For { label: _, targets, iter, body } => {
+				let iter: Type = self.expression(iter);
+				self.iterable(&iter);
+
+				for target in targets {
+					self.bind(target, Binding::Value(Type::Dynamic));
+				}
+
+				let _ = self.block(body);
+			},
+			Statement::Export(statement) | Statement::Doc(_, statement) => {
+				let _ = self.statement(statement);
+			},
+			Statement::ModuleDoc(_) => {},
+			Statement::Break(_) | Statement::Continue(_) => {},
+		}
+
+		Type::named("Null")
+	}
+
+	fn function(
+		&mut self, name: &str, params: &[Param], returns: &Option<String>, body: &Block,
+		is_async: bool,
+	) -> Signature {
+		self.scopes.push(CheckScope::new(&body.0, params, false));
+
+		for param in params {
+			let typer: Type = match &param.typer {
+				Some(typer) => Type::named(typer),
+				None => Type::Dynamic,
+			};
+			self.bind(&param.name, Binding::Value(typer));
+		}
+
+		let result: Type = self.block(body);
+		self.scopes.pop();
+
+		let returns: Type = if is_async {
+			Type::named("Future")
+		} else if body.is_generator() {
+			Type::named("Generator")
+		} else {
+			match returns {
+				Some(returns) => {
+					if let Type::Named(result) = &result {
+						if result != returns {
+							self.error(format!(
+								"return value of {}() must be {}, not {}",
+								name, returns, result
+							));
+						}
+					}
+
+					Type::named(returns)
+				},
+				None => result,
+			}
+		};
+
+		Signature { name: name.to_string(), params: params.to_vec(), returns }
+	}
+
+	fn iterable(&mut self, iter: &Type) {
+		if let Type::Named(typer) = iter {
+			if !iter.is_any(&ITERABLES) {
+				self.error(format!("'{}' object is not iterable", typer));
+			}
+		}
+	}
+
+	// Expressions
+
+	fn expression(&mut self, expression: &Expression) -> Type {
+		match expression {
+			Expression::Identifier(name) => self.lookup_type(name),
+			Expression::Literal(literal) => self.literal(literal),
+			Expression::Prefix(prefix, right) => {
+				let right: Type = self.expression(right);
+				self.prefix(prefix, right)
+			},
+			Expression::Infix(infix, left, right) => {
+				let left: Type = self.expression(left);
+				let right: Type = self.expression(right);
+				self.infix(infix, left, right)
+			},
+			Expression::Fn { params, returns, body, is_async } => {
+				let _ = self.function("<anonymous>", params, returns, body, *is_async);
+				Type::named("Fn")
+			},
+			Expression::Await(expression) => {
+				let _ = self.expression(expression);
+				Type::Dynamic
+			},
+			Expression::Call { function, arguments } => self.call(function, arguments),
+			Expression::Index(left, index) => {
+				let _ = self.expression(left);
+				let _ = self.expression(index);
+				Type::Dynamic
+			},
+			Expression::Slice(left, start, end) => {
+				for bound in start.iter().chain(end.iter()) {
+					let _ = self.expression(bound);
+				}
+
+				match self.expression(left) {
+					left if left.is_any(&["Vec", "String", "Bytes"]) => left,
+					_ => Type::Dynamic,
+				}
+			},
+			Expression::Property(left, property) => {
+				let _ = self.expression(left);
+
+				if let Expression::Call { function: _, arguments } = property.as_ref() {
+					for argument in arguments {
+						let _ = self.expression(argument);
+					}
+				}
+
+				Type::Dynamic
+			},
+		}
+	}
+
+	fn literal(&mut self, literal: &Literal) -> Type {
+		match literal {
+			Literal::Null => Type::named("Null"),
+			Literal::Integer(_) => Type::named("Integer"),
+			Literal::Float(_) => Type::named("Float"),
+			Literal::Boolean(_) => Type::named("Boolean"),
+			Literal::String(_) => Type::named("String"),
+			Literal::Char(_) => Type::named("Char"),
+			Literal::Bytes(_) => Type::named("Bytes"),
+			Literal::Vec(values) | Literal::Tuple(values) | Literal::Set(values) => {
+				for value in values {
+					let _ = self.expression(value);
+				}
+
+				Type::named(match literal {
+					Literal::Vec(_) => "Vec",
+					Literal::Tuple(_) => "Tuple",
+					_ => "Set",
+				})
+			},
+			Literal::HashMap(entries) => {
+				for (key, value) in entries {
+					let _ = self.expression(key);
+					let _ = self.expression(value);
+				}
+
+				Type::named("HashMap")
+			},
+			Literal::VecComprehension(element, clauses) => {
+				self.comprehension(clauses, &[element]);
+				Type::named("Vec")
+			},
+			Literal::SetComprehension(element, clauses) => {
+				self.comprehension(clauses, &[element]);
+				Type::named("Set")
+			},
+			Literal::HashMapComprehension(entry, clauses) => {
+				self.comprehension(clauses, &[&entry.0, &entry.1]);
+				Type::named("HashMap")
+			},
+		}
+	}
+
+	fn comprehension(&mut self, clauses: &[ComprehensionFor], elements: &[&Expression]) {
+		let targets: Vec<Param> = clauses
+			.iter()
+			.flat_map(|clause| clause.targets.iter())
+			.map(|target| Param { name: target.clone(), typer: None })
+			.collect();
+		self.scopes.push(CheckScope::new(&[], &targets, true));
+
+		for clause in clauses {
+			let iter: Type = self.expression(&clause.iter);
+			self.iterable(&iter);
+
+			for target in clause.targets.iter() {
+				self.bind(target, Binding::Value(Type::Dynamic));
+			}
+
+			for condition in clause.conditions.iter() {
+				let _ = self.expression(condition);
+			}
+		}
+
+		for element in elements {
+			let _ = self.expression(element);
+		}
+
+		self.scopes.pop();
+	}
+
+	fn prefix(&mut self, prefix: &Prefix, right: Type) -> Type {
+		match (prefix, &right) {
+			(Prefix::Not, _) => Type::named("Boolean"),
+			(_, Type::Dynamic) => Type::Dynamic,
+			(_, Type::Named(typer)) if typer == "Float" => right,
+			(_, Type::Named(typer)) if typer == "Integer" || typer == "Boolean" =>
+				Type::named("Integer"),
+			(prefix, Type::Named(typer)) => {
+				self.error(format!("bad operand type for unary {}: '{}'", prefix, typer));
+				Type::Dynamic
+			},
+		}
+	}
+
+	fn infix(&mut self, infix: &Infix, left: Type, right: Type) -> Type {
+		match infix {
+			Infix::Equal
+			| Infix::NotEqual
+			| Infix::LessThan
+			| Infix::LessThanEqual
+			| Infix::GreaterThan
+			| Infix::GreaterThanEqual => return Type::named("Boolean"),
+			Infix::In | Infix::NotIn => {
+				if let Type::Named(typer) = &right {
+					if !right.is_any(&ITERABLES) && typer != "ObjectRust" {
+						self.error(format!("argument of type '{}' is not iterable", typer));
+					} else if let (true, Type::Named(item)) = (right.is("String"), &left) {
+						if item != "String" && item != "Char" {
+							self.error(format!(
+								"'in <String>' requires String or Char as left operand, not {}",
+								item
+							));
+						}
+					}
+				}
+
+				return Type::named("Boolean");
+			},
+			_ => {},
+		}
+
+		let (lhs, rhs) = match (&left, &right) {
+			(Type::Named(lhs), Type::Named(rhs)) => (lhs.as_str(), rhs.as_str()),
+			_ => return Type::Dynamic,
+		};
+
+		let numbers: bool = NUMBERS.contains(&lhs) && NUMBERS.contains(&rhs);
+		let result: Option<&str> = match infix {
+			Infix::Plus | Infix::Minus | Infix::Multiply | Infix::Divide if numbers =>
+				Some(if lhs == "Float" || rhs == "Float" { "Float" } else { "Integer" }),
+			Infix::Plus if lhs == "String" && rhs == "String" => Some("String"),
+			Infix::Plus if lhs == "Bytes" && rhs == "Bytes" => Some("Bytes"),
+			Infix::Multiply if lhs == "String" && rhs == "Integer" => Some("String"),
+			Infix::Minus | Infix::BitOr | Infix::BitAnd | Infix::BitXor
+				if lhs == "Set" && rhs == "Set" =>
+				Some("Set"),
+			_ => None,
+		};
+
+		match result {
+			Some(result) => Type::named(result),
+			None => {
+				self.error(format!(
+					"unsupported operand type(s) for {}: '{}' and '{}'",
+					infix, lhs, rhs
+				));
+				Type::Dynamic
+			},
+		}
+	}
+
+	fn call(&mut self, function: &Expression, arguments: &[Expression]) -> Type {
+		let arguments: Vec<Type> =
+			arguments.iter().map(|argument| self.expression(argument)).collect();
+
+		if let Expression::Identifier(name) = function {
+			if let Some(Binding::Fn(signature)) = self.lookup(name) {
+				return self.call_signature(&signature, &arguments);
+			}
+		}
+
+		match self.expression(function) {
+			Type::Named(typer) if typer != "Fn" => {
+				self.error(format!("'{}' object is not callable", typer));
+				Type::Dynamic
+			},
+			_ => Type::Dynamic,
+		}
+	}
+
+	fn call_signature(&mut self, signature: &Signature, arguments: &[Type]) -> Type {
+		if signature.params.len() != arguments.len() {
+			self.error(format!(
+				"{}() expected {} argument, found {}",
+				signature.name,
+				signature.params.len(),
+				arguments.len()
+			));
+			return Type::Dynamic;
+		}
+
+		for (param, argument) in signature.params.iter().zip(arguments) {
+			if let (Some(typer), Type::Named(argument)) = (&param.typer, argument) {
+				if typer != argument {
+					self.error(format!(
+						"argument '{}' of {}() must be {}, not {}",
+						param.name, signature.name, typer, argument
+					));
+				}
+			}
+		}
+
+		signature.returns.clone()
+	}
+}
+
+// CheckScope
+
+impl CheckScope {
+	/// Counts how many times each name is bound by `params` and the
+	/// statements of a scope, loop bodies included.
+	fn new(statements: &[Statement], params: &[Param], inherits: bool) -> Self {
+		let mut counts: HashMap<String, usize> = HashMap::new();
+
+		for param in params {
+			*counts.entry(param.name.clone()).or_insert(0) += 1;
+		}
+
+		count_bindings(statements, &mut counts);
+		Self { counts, bindings: HashMap::new(), inherits }
+	}
+}
+
+fn count_bindings(statements: &[Statement], counts: &mut HashMap<String, usize>) {
+	for statement in statements {
+		match statement {
+			Statement::Let(name, ..)
+			| Statement::LetAlter(name, _)
+			| Statement::Fn { name, .. } => *counts.entry(name.clone()).or_insert(0) += 1,
+			Statement::Export(statement) | Statement::Doc(_, statement) =>
+				count_bindings(std::slice::from_ref(statement), counts),
+			Statement::Import(path, alias) =>
+				*counts.entry(import_name(path, alias)).or_insert(0) += 2,
+			Statement::ImportFrom(_, names) =>
+				for (name, alias) in names {
+					*counts.entry(alias.as_ref().unwrap_or(name).clone()).or_insert(0) += 2;
+				},
+			Statement::For { label: _, targets, iter: _, body } => {
+				for target in targets {
+					// A loop target takes a new value on every iteration.
+					*counts.entry(target.clone()).or_insert(0) += 2;
+				}
+
+				count_bindings(&body.0, counts);
+			},
+			_ => {},
+		}
+	}
+}
+
+fn import_name(path: &str, alias: &Option<String>) -> String {
+	match (alias, std::path::Path::new(path).file_stem()) {
+		(Some(alias), _) => alias.clone(),
+		(None, Some(stem)) => stem.to_string_lossy().to_string(),
+		(None, None) => path.to_string(),
+	}
+}
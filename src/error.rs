@@ -2,27 +2,29 @@
 
 use crate::preludes::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Exception {
 	pub exception_points: Vec<ExceptionPoint>,
 	pub except: Except,
 	pub is_runtime: bool,
+	pub cause: Option<Box<Exception>>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ExceptionPoint {
 	pub module_context: String,
 	pub position: Position,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Except {
 	pub typer: ExceptType,
 	pub message: String,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum ExceptType {
+	Assertion,
 	AttributeError,
 	Eof,
 	Error,
@@ -32,12 +34,22 @@ pub enum ExceptType {
 	Key,
 	KeyboardInterrupt,
 	Name,
+	Timeout,
 	Type,
 	UnexpectedEOF,
+	Value,
 }
 
 impl std::fmt::Display for Exception {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		if let Some(cause) = &self.cause {
+			write!(
+				f,
+				"{}\n\nThe above exception was the direct cause of the following exception:\n\n",
+				cause
+			)?;
+		}
+
 		if self.is_runtime {
 			writeln!(f, "Traceback (most recent call last):")?;
 		}
@@ -78,6 +90,7 @@ impl std::fmt::Display for Except {
 impl std::fmt::Display for ExceptType {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		match self {
+			ExceptType::Assertion => write!(f, "AssertionError"),
 			ExceptType::AttributeError => write!(f, "AttributeError"),
 			ExceptType::Eof => write!(f, "EOFError"),
 			ExceptType::Error => write!(f, "Error"),
@@ -87,15 +100,17 @@ impl std::fmt::Display for ExceptType {
 			ExceptType::Key => write!(f, "KeyError"),
 			ExceptType::KeyboardInterrupt => write!(f, "KeyboardInterrupt"),
 			ExceptType::Name => write!(f, "NameError"),
+			ExceptType::Timeout => write!(f, "TimeoutError"),
 			ExceptType::Type => write!(f, "TypeError"),
 			ExceptType::UnexpectedEOF => write!(f, "UnexpectedEOF"),
+			ExceptType::Value => write!(f, "ValueError"),
 		}
 	}
 }
 
 impl Exception {
 	fn new(except: Except, is_runtime: bool) -> Self {
-		Self { exception_points: Vec::new(), except, is_runtime }
+		Self { exception_points: Vec::new(), except, is_runtime, cause: None }
 	}
 
 	pub fn not_runtime(except: Except) -> Self { Self::new(except, false) }
@@ -105,6 +120,12 @@ impl Exception {
 	pub fn push(&mut self, exception_point: ExceptionPoint) {
 		self.exception_points.push(exception_point);
 	}
+
+	/// Records the exception that led to this one.
+	pub fn caused_by(mut self, cause: Exception) -> Self {
+		self.cause = Some(Box::new(cause));
+		self
+	}
 }
 
 impl ExceptionPoint {
@@ -118,6 +139,10 @@ impl Except {
 		Except { typer, message: message.into() }
 	}
 
+	pub fn assertion<T: Into<String>>(message: T) -> Self {
+		Self::new(ExceptType::Assertion, message)
+	}
+
 	pub fn attribute<T: Into<String>>(message: T) -> Self {
 		Self::new(ExceptType::AttributeError, message)
 	}
@@ -142,9 +167,13 @@ impl Except {
 
 	pub fn name<T: Into<String>>(message: T) -> Self { Self::new(ExceptType::Name, message) }
 
+	pub fn timeout<T: Into<String>>(message: T) -> Self { Self::new(ExceptType::Timeout, message) }
+
 	pub fn type_<T: Into<String>>(message: T) -> Self { Self::new(ExceptType::Type, message) }
 
 	pub fn unexpected_eof<T: Into<String>>(message: T) -> Self {
 		Self::new(ExceptType::UnexpectedEOF, message)
 	}
+
+	pub fn value<T: Into<String>>(message: T) -> Self { Self::new(ExceptType::Value, message) }
 }
@@ -0,0 +1,276 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use std::time::Duration;
+
+use crate::preludes::*;
+
+pub(crate) fn register(interpreter: &mut Interpreter) {
+	interpreter.set_global("next", GFunctionNative::new(Some(String::from("next")), 1, next));
+	interpreter.set_global("items", GFunctionNative::new(Some(String::from("items")), 1, items));
+	interpreter.set_global("set", GFunctionNative::new(Some(String::from("set")), 1, set));
+	interpreter.set_global("vec", GFunctionNative::new(Some(String::from("vec")), 1, vec));
+	interpreter.set_global("dir", GFunctionNative::new(Some(String::from("dir")), 1, dir));
+	interpreter.set_global("help", GFunctionNative::new(Some(String::from("help")), 1, help));
+	interpreter.set_global("ord", GFunctionNative::new(Some(String::from("ord")), 1, ord));
+	interpreter.set_global("chr", GFunctionNative::new(Some(String::from("chr")), 1, chr));
+	interpreter.set_global("encode", GFunctionNative::new(Some(String::from("encode")), 2, encode));
+	interpreter.set_global("decode", GFunctionNative::new(Some(String::from("decode")), 2, decode));
+	interpreter.set_global("sleep", GFunctionNative::new(Some(String::from("sleep")), 1, sleep));
+	interpreter.set_global("gather", GFunctionNative::new(Some(String::from("gather")), 1, gather));
+	interpreter
+		.set_global("timeout", GFunctionNative::new(Some(String::from("timeout")), 2, timeout));
+}
+
+fn next(interpreter: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	match &args[0] {
+		Object::Generator(generator) => match generator.resume(interpreter)? {
+			Some(object) => Ok(object),
+			None => gl_ok!(),
+		},
+		o => Err(Exception::in_runtime(Except::type_(format!(
+			"'{}' object is not an iterator",
+			o.typer()
+		)))),
+	}
+}
+
+fn items(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	match &args[0] {
+		Object::HashMap(hashmap) => gl_ok!(hashmap
+			.iter()
+			.map(|(key, value)| Object::Tuple(vec![key.clone(), value.clone()]))
+			.collect::<Vec<Object>>()),
+		o => Err(Exception::in_runtime(Except::type_(format!(
+			"items() argument must be HashMap, not {}",
+			o.typer()
+		)))),
+	}
+}
+
+fn set(interpreter: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let values: Vec<Object> = GIterator::new(&args[0])?.collect(interpreter)?;
+	gl_ok!(values.into_iter().collect::<GSet>())
+}
+
+fn vec(interpreter: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	gl_ok!(GIterator::new(&args[0])?.collect(interpreter)?)
+}
+
+fn dir(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let names: Vec<String> = match &args[0] {
+		Object::Module(module) => module.public_names(),
+		Object::ModuleNative(module) => {
+			let mut names: Vec<String> = module.context.keys().cloned().collect();
+			names.sort();
+			names
+		},
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"dir() argument must be Module, not {}",
+				o.typer()
+			)))),
+	};
+
+	gl_ok!(names.into_iter().map(Object::String).collect::<Vec<Object>>())
+}
+
+/// The signature and doc comment of a function, or the doc comment of a
+/// module followed by its public names and their docs.
+fn help(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let text: String = match &args[0] {
+		Object::Fn(function) => {
+			let params: Vec<String> = function.params.iter().map(Param::to_string).collect();
+			let signature: String = format!(
+				"{}fn {}({}){}",
+				if function.is_async { "async " } else { "" },
+				function.name.as_deref().unwrap_or("<anonymous>"),
+				params.join(", "),
+				match &function.returns {
+					Some(returns) => format!(" -> {}", returns),
+					None => String::new(),
+				}
+			);
+
+			match &function.doc {
+				Some(doc) => format!("{}\n\n{}", signature, doc),
+				None => signature,
+			}
+		},
+		Object::Module(module) => {
+			let mut text: String = format!("module {}", module.name);
+
+			if let Some(doc) = &module.doc {
+				text.push_str(&format!("\n\n{}", doc));
+			}
+
+			for name in module.public_names() {
+				text.push_str(&format!("\n\n{}", name));
+
+				if let Some(doc) = module.context.docs.get(&name) {
+					for line in doc.lines() {
+						text.push_str(&format!("\n    {}", line));
+					}
+				}
+			}
+
+			text
+		},
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"help() argument must be Fn or Module, not {}",
+				o.typer()
+			)))),
+	};
+
+	gl_ok!(Object::String(text))
+}
+
+fn ord(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	match &args[0] {
+		Object::Char(c) => gl_ok!(Object::Integer((*c as u32).to_bigint().unwrap())),
+		o => Err(Exception::in_runtime(Except::type_(format!(
+			"ord() argument must be Char, not {}",
+			o.typer()
+		)))),
+	}
+}
+
+fn chr(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	match &args[0] {
+		Object::Integer(integer) => match integer.to_u32().and_then(char::from_u32) {
+			Some(c) => gl_ok!(Object::Char(c)),
+			None => Err(Exception::in_runtime(Except::value(format!(
+				"chr() argument {} is not a Unicode scalar value",
+				integer
+			)))),
+		},
+		o => Err(Exception::in_runtime(Except::type_(format!(
+			"chr() argument must be Integer, not {}",
+			o.typer()
+		)))),
+	}
+}
+
+enum Encoding {
+	Utf8,
+	Ascii,
+	Latin1,
+}
+
+fn encoding(function: &str, object: &Object) -> Result<Encoding, Exception> {
+	let name: String = match object {
+		Object::String(name) => name.to_lowercase().replace('_', "-"),
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"{}() encoding must be String, not {}",
+				function,
+				o.typer()
+			)))),
+	};
+
+	match name.as_str() {
+		"utf-8" | "utf8" => Ok(Encoding::Utf8),
+		"ascii" => Ok(Encoding::Ascii),
+		"latin-1" | "latin1" | "iso-8859-1" => Ok(Encoding::Latin1),
+		_ => Err(Exception::in_runtime(Except::value(format!("unknown encoding: {}", name)))),
+	}
+}
+
+fn encode(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let string: &String = match &args[0] {
+		Object::String(string) => string,
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"encode() argument must be String, not {}",
+				o.typer()
+			)))),
+	};
+	let limit: u32 = match encoding("encode", &args[1])? {
+		Encoding::Utf8 => return gl_ok!(Object::Bytes(string.as_bytes().to_vec())),
+		Encoding::Ascii => 0x7f,
+		Encoding::Latin1 => 0xff,
+	};
+
+	match string.chars().find(|c| *c as u32 > limit) {
+		Some(c) => Err(Exception::in_runtime(Except::value(format!(
+			"can't encode character {:?} in {}",
+			c, args[1]
+		)))),
+		None => gl_ok!(Object::Bytes(string.chars().map(|c| c as u8).collect())),
+	}
+}
+
+fn decode(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let bytes: &Vec<u8> = match &args[0] {
+		Object::Bytes(bytes) => bytes,
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"decode() argument must be Bytes, not {}",
+				o.typer()
+			)))),
+	};
+
+	let string: Option<String> = match encoding("decode", &args[1])? {
+		Encoding::Utf8 => String::from_utf8(bytes.clone()).ok(),
+		Encoding::Ascii if bytes.is_ascii() => Some(bytes.iter().map(|b| *b as char).collect()),
+		Encoding::Ascii => None,
+		Encoding::Latin1 => Some(bytes.iter().map(|b| *b as char).collect()),
+	};
+
+	match string {
+		Some(string) => gl_ok!(Object::String(string)),
+		None =>
+			Err(Exception::in_runtime(Except::value(format!("can't decode bytes as {}", args[1])))),
+	}
+}
+
+fn sleep(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	gl_ok!(GFuture::sleep(milliseconds("sleep", &args[0])?))
+}
+
+fn gather(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let objects: &Vec<Object> = match &args[0] {
+		Object::Vec(vector) => vector,
+		Object::Tuple(values) => values,
+		o =>
+			return Err(Exception::in_runtime(Except::type_(format!(
+				"gather() argument must be Vec or Tuple, not {}",
+				o.typer()
+			)))),
+	};
+
+	let mut futures: Vec<GFuture> = Vec::new();
+	for object in objects.iter() {
+		match object {
+			Object::Future(future) => futures.push(future.clone()),
+			o => return Err(not_awaitable(o)),
+		}
+	}
+
+	gl_ok!(GFuture::gather(futures))
+}
+
+fn timeout(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	match &args[0] {
+		Object::Future(future) =>
+			gl_ok!(GFuture::timeout(future.clone(), milliseconds("timeout", &args[1])?)),
+		o => Err(not_awaitable(o)),
+	}
+}
+
+fn milliseconds(function: &str, object: &Object) -> Result<Duration, Exception> {
+	match object {
+		Object::Integer(integer) => match integer.to_u64() {
+			Some(milliseconds) => Ok(Duration::from_millis(milliseconds)),
+			None => Err(Exception::in_runtime(Except::value(format!(
+				"{}() milliseconds must be a non-negative Integer",
+				function
+			)))),
+		},
+		o => Err(Exception::in_runtime(Except::type_(format!(
+			"{}() milliseconds must be Integer, not {}",
+			function,
+			o.typer()
+		)))),
+	}
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use std::time::{Duration, Instant};
+
+use crate::preludes::*;
+
+/// How long the loop sleeps when a future is pending and nothing asked to
+/// be woken at a specific time.
+const IDLE_TICK: Duration = Duration::from_millis(1);
+
+pub(crate) struct EventLoop {
+	next_wakeup: Option<Instant>,
+}
+
+impl EventLoop {
+	pub(crate) fn new() -> Self { Self { next_wakeup: None } }
+}
+
+impl Interpreter {
+	/// Runs the cooperative event loop until `future` completes, and returns
+	/// its value. Every pending future reachable from it (through `await`,
+	/// `gather` or `timeout`) is polled on each turn of the loop.
+	pub fn run_until_complete(&mut self, future: Object) -> GLResult {
+		let future: GFuture = match future {
+			Object::Future(future) => future,
+			o => return Err(not_awaitable(&o)),
+		};
+
+		loop {
+			self.event_loop.next_wakeup = None;
+
+			if let Some(object) = future.poll(self)? {
+				return Ok(object);
+			}
+
+			let now: Instant = Instant::now();
+			std::thread::sleep(match self.event_loop.next_wakeup {
+				Some(wakeup) => wakeup.saturating_duration_since(now),
+				None => IDLE_TICK,
+			});
+		}
+	}
+
+	/// Asks the event loop to poll again no later than `instant`.
+	pub fn wake_at(&mut self, instant: Instant) {
+		self.event_loop.next_wakeup = match self.event_loop.next_wakeup {
+			Some(wakeup) if wakeup <= instant => Some(wakeup),
+			_ => Some(instant),
+		};
+	}
+}
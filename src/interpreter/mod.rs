@@ -1,10 +1,33 @@
 // Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
 
+use std::path::PathBuf;
+
 use crate::preludes::*;
 
+mod builtins;
+mod event_loop;
+mod modules;
+
+use event_loop::EventLoop;
+pub(crate) use modules::{module_member, module_name, path_stem};
+
 pub struct Interpreter {
 	pub(crate) scopes: ScopeStack,
 	pub(crate) current_level: usize,
+	pub(crate) event_loop: EventLoop,
+	pub(crate) control: Option<Control>,
+	pub(crate) native_modules: HashMap<String, GModuleNative>,
+	pub(crate) search_paths: Vec<PathBuf>,
+	pub(crate) files: Vec<PathBuf>,
+	pub(crate) modules: HashMap<PathBuf, Object>,
+	pub(crate) importing: Vec<(PathBuf, PathBuf)>,
+	pub(crate) abandoned: Abandoned,
+}
+
+/// A `break` or `continue` on its way to the loop it names.
+pub(crate) enum Control {
+	Break(Option<String>),
+	Continue(Option<String>),
 }
 
 #[derive(Clone, PartialEq)]
@@ -16,55 +39,109 @@ pub struct ScopeStack {
 #[derive(Clone, PartialEq)]
 pub struct Scope {
 	pub(crate) store: HashMap<String, Object>,
+	pub(crate) defers: Vec<Expression>,
+	pub(crate) exports: HashSet<String>,
+	pub(crate) doc: Option<String>,
+	pub(crate) docs: HashMap<String, String>,
+	/// The types names were declared with, checked again when reassigned.
+	pub(crate) types: HashMap<String, String>,
 }
 
 // Interpreter
 
 impl Interpreter {
-	pub fn new() -> Self { Self { scopes: ScopeStack::new(), current_level: 0 } }
+	pub fn new() -> Self {
+		let mut interpreter: Self = Self {
+			scopes: ScopeStack::new(),
+			current_level: 0,
+			event_loop: EventLoop::new(),
+			control: None,
+			native_modules: HashMap::new(),
+			search_paths: Vec::new(),
+			files: Vec::new(),
+			modules: HashMap::new(),
+			importing: Vec::new(),
+			abandoned: Rc::new(RefCell::new(Vec::new())),
+		};
+		builtins::register(&mut interpreter);
+		interpreter
+	}
 
 	pub fn eval<T: Into<String>>(&mut self, source: T) -> GLResult {
-		let mut result: Object = Object::Null;
-		self.scopes.create_scope();
-		self.current_level += 1;
+		self.run(Source::from_string(source), "eval")
+	}
 
-		let filename: String = "eval".to_string();
-		let source: Source = Source::from_string(source);
-		let lexer: Lexer = Lexer::new(source, &filename);
-		let mut parser: Parser = Parser::new(lexer)?;
+	/// Runs a script file as the main module. While it runs, importing the
+	/// file itself is a circular import; once it is done, imports of it
+	/// share the module it left.
+	pub fn script<T: Into<String>>(&mut self, filename: T) -> GLResult {
+		let filename: String = filename.into();
+		let path: PathBuf = PathBuf::from(&filename);
+		let canonical: PathBuf = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
 
-		loop {
-			match parser.next()? {
-				Some(statement) => result = statement.eval(self)?,
-				None => break,
-			}
+		self.importing.push((canonical.clone(), path));
+		let result: GLResult = self.script_file(&filename);
+		self.importing.pop();
+
+		if result.is_ok() {
+			let module: Object = self.script_module(path_stem(&canonical), filename);
+			self.modules.insert(canonical, module);
 		}
 
-		self.current_level -= 1;
+		result
+	}
+
+	pub(crate) fn script_file(&mut self, filename: &str) -> GLResult {
+		let source: Source = match Source::from_filename(filename) {
+			Ok(source) => source,
+			Err(err) => {
+				let mut exception: Exception = Exception::in_runtime(Except::error(format!(
+					"can't open file '{}': {}",
+					filename, err
+				)));
+				exception.push(ExceptionPoint::new(filename, Position::default()));
+				return Err(exception);
+			},
+		};
 
-		gl_ok!(result)
+		// The module scope must land just above the caller's, where
+		// `script_module` picks it up.
+		self.scopes.stack.truncate(self.current_level + 1);
+		self.files.push(PathBuf::from(filename));
+		let result: GLResult = self.run(source, filename);
+		self.files.pop();
+
+		result
 	}
 
-	pub fn script<T: Into<String>>(&mut self, filename: T) -> GLResult {
+	fn run(&mut self, source: Source, filename: &str) -> GLResult {
+		let lexer: Lexer = Lexer::new(source, filename);
+		let mut parser: Parser = Parser::new(lexer)?;
+
 		let mut result: Object = Object::Null;
 		self.scopes.create_scope();
 		self.current_level += 1;
 
-		let filename: String = filename.into();
-		let source: Source = Source::from_filename(&filename).unwrap();
-		let lexer: Lexer = Lexer::new(source, &filename);
-		let mut parser: Parser = Parser::new(lexer)?;
-
-		loop {
-			match parser.next()? {
-				Some(statement) => result = statement.eval(self)?,
-				None => break,
+		let result: GLResult = loop {
+			match parser.next_positioned() {
+				Ok(Some((position, statement))) => match statement.eval(self) {
+					Ok(object) => result = object,
+					Err(mut exception) => {
+						if exception.is_runtime {
+							exception.push(ExceptionPoint::new(filename, position));
+						}
+						break Err(exception);
+					},
+				},
+				Ok(None) => break Ok(result),
+				Err(exception) => break Err(exception),
 			}
-		}
+		};
+		let result: GLResult = self.run_defers(result);
 
 		self.current_level -= 1;
 
-		gl_ok!(result)
+		result
 	}
 
 	pub fn get<T: Into<String>>(&self, identifier: T) -> Option<Object> {
@@ -94,6 +171,101 @@ impl Interpreter {
 	pub fn set_global<T: Into<String>, O: Into<Object>>(&mut self, name: T, value: O) {
 		self.scopes.global.set(name.into(), value.into());
 	}
+
+	pub(crate) fn push_scope(&mut self, scope: Scope) {
+		self.scopes.stack.truncate(self.current_level + 1);
+		self.scopes.push_scope(scope);
+		self.current_level += 1;
+	}
+
+	pub(crate) fn export(&mut self, name: &str) {
+		self.scopes.stack[self.current_level].exports.insert(name.to_string());
+	}
+
+	/// Records the doc comment of a name bound in the current scope; a
+	/// function also carries it on its `GFunction`.
+	pub(crate) fn document(&mut self, name: &str, doc: &str) {
+		let scope: &mut Scope = &mut self.scopes.stack[self.current_level];
+		scope.docs.insert(name.to_string(), doc.to_string());
+
+		if let Some(Object::Fn(function)) = scope.store.get_mut(name) {
+			function.doc = Some(doc.to_string());
+		}
+	}
+
+	pub(crate) fn document_module(&mut self, doc: &str) {
+		let scope: &mut Scope = &mut self.scopes.stack[self.current_level];
+		scope.doc = Some(match scope.doc.take() {
+			Some(previous) => format!("{}\n{}", previous, doc),
+			None => doc.to_string(),
+		});
+	}
+
+	pub(crate) fn defer(&mut self, expression: Expression) {
+		self.scopes.stack[self.current_level].defers.push(expression);
+	}
+
+	/// Runs the expressions deferred in the current scope, last first. An
+	/// exception already leaving the scope wins over one raised by a defer.
+	pub(crate) fn run_defers<T>(&mut self, result: Result<T, Exception>) -> Result<T, Exception> {
+		let mut deferred: Result<(), Exception> = Ok(());
+
+		while let Some(expression) = self.scopes.stack[self.current_level].defers.pop() {
+			if let Err(exception) = expression.eval(self) {
+				if deferred.is_ok() {
+					deferred = Err(exception);
+				}
+			}
+		}
+
+		result.and_then(|object| deferred.map(|_| object))
+	}
+
+	/// Starts collecting the defers of a block that runs in the current scope,
+	/// like the body of a `for` loop, returning the ones collected so far.
+	pub(crate) fn begin_defers(&mut self) -> Vec<Expression> {
+		std::mem::take(&mut self.scopes.stack[self.current_level].defers)
+	}
+
+	/// Runs the defers of the block `begin_defers` started and brings back
+	/// the ones collected before it.
+	pub(crate) fn end_defers<T>(
+		&mut self, defers: Vec<Expression>, result: Result<T, Exception>,
+	) -> Result<T, Exception> {
+		let result: Result<T, Exception> = self.run_defers(result);
+		self.scopes.stack[self.current_level].defers = defers;
+		result
+	}
+
+	pub(crate) fn pop_scope(&mut self) -> Scope {
+		self.scopes.stack.truncate(self.current_level + 1);
+		self.current_level -= 1;
+		self.scopes.stack.pop().unwrap_or_else(Scope::new)
+	}
+
+	/// Cleans up after the generators dropped while paused, running what
+	/// their bodies still owe. An exception already on its way out wins.
+	pub(crate) fn close_abandoned<T>(
+		&mut self, result: Result<T, Exception>,
+	) -> Result<T, Exception> {
+		let mut result: Result<T, Exception> = result;
+
+		loop {
+			let abandoned: Option<Resumable> = self.abandoned.borrow_mut().pop();
+			let mut resumable: Resumable = match abandoned {
+				Some(resumable) => resumable,
+				None => break,
+			};
+
+			if let Err(exception) = resumable.close(self) {
+				if result.is_ok() {
+					result = Err(exception);
+				}
+			}
+		}
+
+		result
+	}
 }
 
 // ScopeStack
@@ -113,7 +285,16 @@ impl ScopeStack {
 // Scope
 
 impl Scope {
-	pub fn new() -> Self { Self { store: HashMap::new() } }
+	pub fn new() -> Self {
+		Self {
+			store: HashMap::new(),
+			defers: Vec::new(),
+			exports: HashSet::new(),
+			doc: None,
+			docs: HashMap::new(),
+			types: HashMap::new(),
+		}
+	}
 
 	pub fn get<T: Into<String>>(&self, identifier: T) -> Option<Object> {
 		let identifier: String = identifier.into();
@@ -126,4 +307,12 @@ impl Scope {
 	pub fn set<T: Into<String>>(&mut self, name: T, value: Object) {
 		self.store.insert(name.into(), value);
 	}
+
+	/// Records the type `name` is declared with; `None` drops an earlier one.
+	pub(crate) fn declare(&mut self, name: &str, typer: &Option<String>) {
+		match typer {
+			Some(typer) => self.types.insert(name.to_string(), typer.clone()),
+			None => self.types.remove(name),
+		};
+	}
 }
@@ -0,0 +1,206 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use std::path::{Path, PathBuf};
+
+use libloading::Library;
+
+use crate::preludes::*;
+
+impl Interpreter {
+	/// Makes a native module importable by its name, as in `import "math"`.
+	pub fn register_module(&mut self, module: GModuleNative) {
+		self.native_modules.insert(module.name.clone(), module);
+	}
+
+	/// Adds a directory searched for modules after the importing file's
+	/// own directory and before the directories listed in `GL_PATH`.
+	pub fn add_search_path<P: Into<PathBuf>>(&mut self, path: P) {
+		self.search_paths.push(path.into());
+	}
+
+	/// Loads the module an `import` names: a registered native module, a
+	/// `.gl` script or a dynamic library. A file runs once; later imports of
+	/// the same canonical path share the cached module.
+	pub(crate) fn import_module(&mut self, name: &str) -> GLResult {
+		if let Some(module) = self.native_modules.get(name) {
+			return gl_ok!(Object::ModuleNative(module.clone()));
+		}
+
+		let path: PathBuf = self.resolve_module(name)?;
+		let canonical: PathBuf = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+
+		if let Some(module) = self.modules.get(&canonical) {
+			return gl_ok!(module.clone());
+		}
+
+		if let Some(start) =
+			self.importing.iter().position(|(importing, _)| importing == &canonical)
+		{
+			let chain: Vec<String> = self.importing[start..]
+				.iter()
+				.map(|(_, path)| path.display().to_string())
+				.chain(std::iter::once(path.display().to_string()))
+				.collect();
+			let mut exception: Exception = Exception::in_runtime(Except::import(format!(
+				"circular import: {}",
+				chain.join(" -> ")
+			)));
+			exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+			return Err(exception);
+		}
+
+		self.importing.push((canonical.clone(), path.clone()));
+		let module: GLResult = self.load_module(name, &path);
+		self.importing.pop();
+
+		let module: Object = match module {
+			Ok(module) => module,
+			// An import error from a nested import already names its module.
+			Err(exception) if matches!(exception.except.typer, ExceptType::Import) =>
+				return Err(exception),
+			Err(exception) => {
+				let mut import_exception: Exception =
+					Exception::in_runtime(Except::import(format!("failed to import '{}'", name)));
+				import_exception
+					.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+				return Err(import_exception.caused_by(exception));
+			},
+		};
+		self.modules.insert(canonical, module.clone());
+
+		gl_ok!(module)
+	}
+
+	fn load_module(&mut self, name: &str, path: &Path) -> GLResult {
+		let path_string: String = path.to_string_lossy().to_string();
+		let module_name: String = path_stem(Path::new(name));
+
+		if path.extension().is_some_and(|extension| extension == "gl") {
+			self.script_file(&path_string)?;
+			return gl_ok!(self.script_module(module_name, path_string));
+		}
+
+		let dynlibrary: Library = unsafe {
+			match Library::new(path) {
+				Ok(dynlibrary) => dynlibrary,
+				Err(err) => {
+					let mut exception: Exception = Exception::in_runtime(Except::import(format!(
+						"failed to import '{}': {}",
+						name, err
+					)));
+					exception
+						.push(ExceptionPoint::new("&self.module_context", Position::default()));
+					return Err(exception);
+				},
+			}
+		};
+
+		let moduledynlibrary: ModuleDynLibrary = ModuleDynLibrary::new(
+			&module_name,
+			&path_string,
+			Rc::new(RefCell::new(dynlibrary)),
+			Rc::new(RefCell::new(Scope::new())),
+		);
+
+		if let Ok(Object::FnNative(GFunctionNative { name: _, params_len: _, body })) =
+			moduledynlibrary.get_attr("gl_plugin_init")
+		{
+			body(self, Vec::new())?;
+		};
+
+		gl_ok!(Object::ModuleDynLibrary(moduledynlibrary))
+	}
+
+	/// The module a script just run by `script_file` left behind.
+	pub(crate) fn script_module(&self, name: String, path: String) -> Object {
+		Object::Module(GModule::new(
+			name,
+			path,
+			self.scopes.stack.get(self.current_level + 1).cloned().unwrap_or_else(Scope::new),
+		))
+	}
+
+	/// Finds the file an `import` names. The importing file's directory is
+	/// tried first, then the search paths, then `GL_PATH`; a name without an
+	/// extension may be `name.gl`, `name/mod.gl` or a dynamic library.
+	fn resolve_module(&self, name: &str) -> Result<PathBuf, Exception> {
+		let mut directories: Vec<PathBuf> = vec![match self.files.last() {
+			Some(file) => file.parent().map(Path::to_path_buf).unwrap_or_default(),
+			None => PathBuf::new(),
+		}];
+		directories.extend(self.search_paths.iter().cloned());
+
+		if let Some(gl_path) = std::env::var_os("GL_PATH") {
+			directories.extend(std::env::split_paths(&gl_path));
+		}
+
+		let path: &Path = Path::new(name);
+		let mut tried: Vec<PathBuf> = Vec::new();
+
+		for directory in directories {
+			let candidates: Vec<PathBuf> = if path.extension().is_some() {
+				vec![directory.join(path)]
+			} else {
+				vec![
+					directory.join(path.with_extension("gl")),
+					directory.join(path).join("mod.gl"),
+					directory.join(path).with_file_name(format!(
+						"{}{}{}",
+						std::env::consts::DLL_PREFIX,
+						path_stem(path),
+						std::env::consts::DLL_SUFFIX
+					)),
+				]
+			};
+
+			for candidate in candidates {
+				if candidate.is_file() {
+					return Ok(candidate);
+				}
+
+				tried.push(candidate);
+			}
+
+			if path.is_absolute() {
+				break;
+			}
+		}
+
+		let tried: Vec<String> =
+			tried.iter().map(|path| format!("\n  {}", path.display())).collect();
+		let mut exception: Exception = Exception::in_runtime(Except::import(format!(
+			"no module named '{}', tried:{}",
+			name,
+			tried.concat()
+		)));
+		exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+		Err(exception)
+	}
+}
+
+pub(crate) fn path_stem(path: &Path) -> String {
+	match path.file_stem() {
+		Some(stem) => stem.to_string_lossy().to_string(),
+		None => path.to_string_lossy().to_string(),
+	}
+}
+
+/// The name a module is bound to when imported without an alias.
+pub(crate) fn module_name(module: &Object) -> String {
+	match module {
+		Object::Module(module) => module.name.clone(),
+		Object::ModuleNative(module) => module.name.clone(),
+		Object::ModuleDynLibrary(module) => module.get_name(),
+		o => o.typer().to_string(),
+	}
+}
+
+/// A public member of a module, as `from ... import` sees it.
+pub(crate) fn module_member(module: &Object, name: &str) -> Option<Object> {
+	match module {
+		Object::Module(module) if module.is_public(name) => module.get(name.to_string()),
+		Object::ModuleNative(module) => module.get(name.to_string()),
+		Object::ModuleDynLibrary(module) => module.get_attr(name).ok(),
+		_ => None,
+	}
+}
@@ -0,0 +1,43 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::lexer::ResultLexer;
+use crate::preludes::*;
+
+impl Lexer {
+	/// Lexes a char literal whose opening `'` starts at `position_start`.
+	pub fn lexe_char(&mut self, position_start: Position) -> ResultLexer {
+		let char_literal: char = match self.cchar {
+			'\'' => {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::invalid_syntax("empty char literal"));
+				exception.push(ExceptionPoint::new(&self.module, position_start));
+				return Err(exception);
+			},
+			'\\' => {
+				let escape_start: Position = self.position.copy();
+				self.next_char(); // \
+				self.lexe_escape(escape_start)?
+			},
+			c => c,
+		};
+		self.next_char();
+
+		if self.cchar != '\'' {
+			let mut exception: Exception = Exception::not_runtime(match self.is_eof_char() {
+				true => Except::unexpected_eof("unterminated char literal"),
+				false => Except::invalid_syntax("char literal must be one character"),
+			});
+			exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+			return Err(exception);
+		}
+
+		self.next_char(); // '
+		self.make_token_and_push(
+			TokenType::CHAR(char_literal),
+			position_start,
+			self.position.copy(),
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::lexer::ResultLexer;
+use crate::preludes::*;
+
+impl Lexer {
+	/// Lexes `//`. A `///` line documents the item after it and a `//!` line
+	/// documents the enclosing module; both keep their text. Any other line
+	/// comment is skipped up to, but not including, its newline.
+	pub fn lexe_line_comment(&mut self) -> ResultLexer {
+		let position_start: Position = self.position.copy();
+		self.next_char(); // /
+		self.next_char(); // /
+
+		let doc: Option<fn(String) -> TokenType> = match (self.cchar, self.nchar) {
+			('/', c) if c != '/' => Some(TokenType::DocComment),
+			('!', _) => Some(TokenType::ModuleDocComment),
+			_ => None,
+		};
+
+		match doc {
+			Some(doc) => {
+				self.next_char(); // / or !
+				let token_type: TokenType = doc(self.lexe_rest_of_line());
+				self.make_token_and_push(token_type, position_start, self.position.copy());
+			},
+			None => {
+				self.lexe_rest_of_line();
+				self.keep_trivia();
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Skips a `/* ... */` comment. Block comments nest, so every `/*` inside
+	/// needs its own `*/`.
+	pub fn lexe_block_comment(&mut self) -> ResultLexer {
+		let position_start: Position = self.position.copy();
+		let mut depth: usize = 0;
+
+		loop {
+			if self.is_eof_char() {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::unexpected_eof("unterminated block comment"));
+				exception.push(ExceptionPoint::new(&self.module, position_start));
+				return Err(exception);
+			}
+
+			if self.cchar == '/' && self.nchar == '*' {
+				depth += 1;
+				self.next_char(); // /
+				self.next_char(); // *
+			} else if self.cchar == '*' && self.nchar == '/' {
+				depth -= 1;
+				self.next_char(); // *
+				self.next_char(); // /
+
+				if depth == 0 {
+					self.keep_trivia();
+					return Ok(());
+				}
+			} else {
+				self.next_char_in_text();
+			}
+		}
+	}
+
+	/// Skips a `#!` interpreter line at the very start of a script. Its
+	/// newline is left to be lexed, so later lines keep their numbers.
+	pub fn lexe_shebang(&mut self) {
+		self.lexe_rest_of_line();
+		self.keep_trivia();
+	}
+
+	fn lexe_rest_of_line(&mut self) -> String {
+		let mut text: String = String::new();
+
+		while !self.is_eof_char() && self.cchar != '\n' {
+			text.push(self.cchar);
+			self.next_char();
+		}
+
+		let text: &str = text.strip_suffix('\r').unwrap_or(&text);
+		text.strip_prefix(' ').unwrap_or(text).to_string()
+	}
+}
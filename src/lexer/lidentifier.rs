@@ -21,7 +21,22 @@ impl Lexer {
 			i if i == "false" => TokenType::BOOLEAN(false),
 			i if i == "let" => TokenType::LET,
 			i if i == "fn" => TokenType::FN,
+			i if i == "async" => TokenType::ASYNC,
+			i if i == "await" => TokenType::AWAIT,
 			i if i == "import" => TokenType::IMPORT,
+			i if i == "from" => TokenType::FROM,
+			i if i == "export" => TokenType::EXPORT,
+			i if i == "assert" => TokenType::ASSERT,
+			i if i == "defer" => TokenType::DEFER,
+			i if i == "with" => TokenType::WITH,
+			i if i == "as" => TokenType::AS,
+			i if i == "yield" => TokenType::YIELD,
+			i if i == "for" => TokenType::FOR,
+			i if i == "break" => TokenType::BREAK,
+			i if i == "continue" => TokenType::CONTINUE,
+			i if i == "if" => TokenType::IF,
+			i if i == "in" => TokenType::IN,
+			i if i == "not" => TokenType::NOT,
 			identifier => TokenType::IDENTIFIER(identifier),
 		};
 
@@ -0,0 +1,43 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::lexer::ResultLexer;
+use crate::preludes::*;
+
+impl Lexer {
+	pub fn lexe_label(&mut self) -> ResultLexer {
+		let position_start: Position = self.position.copy();
+		self.next_char(); // '
+
+		if self.cchar == '\\' || self.cchar == '\'' || self.nchar == '\'' {
+			return self.lexe_char(position_start);
+		}
+
+		if !(self.cchar == '_' || self.cchar.is_alphabetic()) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected label name after '\\''"));
+			exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+			return Err(exception);
+		}
+
+		let mut label: String = String::new();
+
+		while !self.is_eof_char()
+			&& (self.cchar.is_alphabetic() || self.cchar.is_digit(10) || self.cchar == '_')
+		{
+			label.push(self.cchar);
+			self.next_char()
+		}
+
+		// `'ab'` is a char literal with too many characters, not a label.
+		if self.cchar == '\'' {
+			let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+				"char literal must be one character",
+			));
+			exception.push(ExceptionPoint::new(&self.module, position_start));
+			return Err(exception);
+		}
+
+		self.make_token_and_push(TokenType::LABEL(label), position_start, self.position.copy());
+		Ok(())
+	}
+}
@@ -6,23 +6,68 @@ use crate::preludes::*;
 impl Lexer {
 	pub fn lexe_number(&mut self) -> ResultLexer {
 		let position_start: Position = self.position.copy();
-		let mut number_literal: String = String::new();
-		let mut dot: bool = false;
 
-		while !self.is_eof_char() && (self.cchar.is_digit(10) || self.cchar == '.') {
-			if self.cchar == '.' {
-				if dot {
-					return self.invalid_syntax_err();
+		if self.cchar == '0' && matches!(self.nchar, 'x' | 'b' | 'o') {
+			return self.lexe_radix_integer(position_start);
+		}
+
+		let mut number_literal: String = self.lexe_digits(10);
+		let mut float: bool = false;
+
+		// `1.e5`, `1.` and `1._5` are an integer and a property access, so
+		// only a digit or a repeating group makes the dot part of the number.
+		if self.cchar == '.' && (self.nchar.is_ascii_digit() || self.nchar == '(') {
+			float = true;
+			number_literal.push('.');
+			self.next_char(); // .
+			number_literal.push_str(&self.lexe_digits(10));
+
+			if self.cchar == '(' {
+				number_literal.push('(');
+				self.next_char(); // (
+				let repeating: String = self.lexe_digits(10);
+
+				if repeating.is_empty() || self.cchar != ')' {
+					return self.number_err("expected repeating digits and ')' after '('");
 				}
-				dot = true;
+
+				number_literal.push_str(&repeating);
+				number_literal.push(')');
+				self.next_char(); // )
 			}
 
-			number_literal.push(self.cchar);
-			self.next_char();
+			if self.cchar == '.' {
+				return self.invalid_syntax_err();
+			}
+		}
+
+		if self.cchar == 'e' || self.cchar == 'E' {
+			float = true;
+			number_literal.push('e');
+			self.next_char(); // e
+
+			if self.cchar == '+' || self.cchar == '-' {
+				number_literal.push(self.cchar);
+				self.next_char();
+			}
+
+			let exponent_start: Position = self.position.copy();
+			let exponent: String = self.lexe_digits(10);
+			if exponent.is_empty() {
+				return self.number_err("expected exponent digits");
+			}
+			// Floats are exact rationals, so a huge exponent means a huge number.
+			if exponent.trim_start_matches('0').len() > 4 {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::invalid_syntax("exponent is too large"));
+				exception.push(ExceptionPoint::new(&self.module, exponent_start));
+				return Err(exception);
+			}
+			number_literal.push_str(&exponent);
 		}
 
 		self.make_token_and_push(
-			if !dot {
+			if !float {
 				TokenType::INTEGER(number_literal)
 			} else {
 				TokenType::FLOAT(number_literal)
@@ -33,4 +78,54 @@ impl Lexer {
 
 		Ok(())
 	}
+
+	/// Lexes `0x`, `0b` and `0o` integers, keeping the prefix in the token.
+	fn lexe_radix_integer(&mut self, position_start: Position) -> ResultLexer {
+		let radix: u32 = match self.nchar {
+			'x' => 16,
+			'b' => 2,
+			_ => 8,
+		};
+		let mut number_literal: String = format!("0{}", self.nchar);
+		self.next_char(); // 0
+		self.next_char(); // x, b or o
+
+		let digits: String = self.lexe_digits(radix);
+		if digits.is_empty() {
+			return self.number_err(format!("expected digits after '{}'", number_literal));
+		}
+		if self.cchar.is_alphanumeric() {
+			return self
+				.number_err(format!("invalid digit '{}' in base {} literal", self.cchar, radix));
+		}
+		number_literal.push_str(&digits);
+
+		self.make_token_and_push(
+			TokenType::INTEGER(number_literal),
+			position_start,
+			self.position.copy(),
+		);
+
+		Ok(())
+	}
+
+	/// Reads digits of `radix`, skipping `_` separators after the first digit.
+	fn lexe_digits(&mut self, radix: u32) -> String {
+		let mut digits: String = String::new();
+
+		while self.cchar.is_digit(radix) || (self.cchar == '_' && !digits.is_empty()) {
+			if self.cchar != '_' {
+				digits.push(self.cchar);
+			}
+			self.next_char();
+		}
+
+		digits
+	}
+
+	fn number_err<T: Into<String>>(&self, message: T) -> ResultLexer {
+		let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(message));
+		exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+		Err(exception)
+	}
 }
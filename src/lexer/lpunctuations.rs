@@ -9,6 +9,12 @@ impl Lexer {
 
 		if self.cchar == '"' {
 			return self.lexe_string();
+		} else if self.cchar == '\'' {
+			return self.lexe_label();
+		} else if self.cchar == '/' && self.nchar == '/' {
+			return self.lexe_line_comment();
+		} else if self.cchar == '/' && self.nchar == '*' {
+			return self.lexe_block_comment();
 		} else if self.operators() {
 			return Ok(());
 		}
@@ -28,6 +34,10 @@ impl Lexer {
 			c if c == ']' => TokenType::RightBracket,
 			c if c == '{' => TokenType::LeftBrace,
 			c if c == '}' => TokenType::RightBrace,
+			c if c == '#' && self.nchar == '{' => {
+				self.next_char();
+				TokenType::HashBrace
+			},
 			_ => return self.invalid_syntax_err(),
 		};
 
@@ -41,21 +51,16 @@ impl Lexer {
 
 		let token_type: TokenType = match self.cchar {
 			c if c == '+' => TokenType::PLUS,
-			c if c == '-' => TokenType::MINUS,
-			c if c == '*' && self.nchar == '/' => {
+			c if c == '-' && self.nchar == '>' => {
 				self.next_char();
-				TokenType::CommentBlockClose
+				TokenType::ARROW
 			},
+			c if c == '-' => TokenType::MINUS,
 			c if c == '*' => TokenType::ASTERISK,
-			c if c == '/' && self.nchar == '/' => {
-				self.next_char();
-				TokenType::CommentLine
-			},
-			c if c == '/' && self.nchar == '*' => {
-				self.next_char();
-				TokenType::CommentBlockOpen
-			},
 			c if c == '/' => TokenType::SLASH,
+			c if c == '|' => TokenType::PIPE,
+			c if c == '&' => TokenType::AMPERSAND,
+			c if c == '^' => TokenType::CARET,
 			c if c == '=' && self.nchar == '=' => {
 				self.next_char();
 				TokenType::EQUAL
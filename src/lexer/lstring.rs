@@ -6,51 +6,318 @@ use crate::preludes::*;
 impl Lexer {
 	pub fn lexe_string(&mut self) -> ResultLexer {
 		let position_start: Position = self.position.copy();
+		self.next_char(); // "
+
+		let triple: bool = self.cchar == '"' && self.nchar == '"';
+		if triple {
+			self.next_char(); // "
+			self.next_char(); // "
+		}
+
+		let mut text: Vec<(char, bool)> = Vec::new();
+
+		loop {
+			if self.is_eof_char() {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::unexpected_eof(match triple {
+						true => "unterminated triple quote string",
+						false => "unterminated double quote string",
+					}));
+				exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+				return Err(exception);
+			}
+
+			if self.cchar == '"' {
+				if !triple {
+					self.next_char(); // "
+					break;
+				}
+
+				// Only the last three of a run of quotes close the string.
+				let mut quotes: usize = 0;
+				while self.cchar == '"' {
+					quotes += 1;
+					self.next_char();
+				}
+
+				if quotes >= 3 {
+					text.extend(std::iter::repeat_n(('"', false), quotes - 3));
+					break;
+				}
+
+				text.extend(std::iter::repeat_n(('"', false), quotes));
+				continue;
+			}
+
+			if self.cchar == '\\' {
+				let escape_start: Position = self.position.copy();
+				self.next_char(); // \
+
+				if self.cchar == '\n' || (self.cchar == '\r' && self.nchar == '\n') {
+					// A line continuation drops the newline and the next line's indentation.
+					while self.cchar.is_whitespace() {
+						self.next_char_in_text();
+					}
+					continue;
+				}
+
+				text.push((self.lexe_escape(escape_start)?, true));
+			} else {
+				text.push((self.cchar, false));
+			}
+			self.next_char_in_text()
+		}
+
+		let string_literal: String = match triple {
+			true => strip_indentation(text),
+			false => text.into_iter().map(|(c, _)| c).collect(),
+		};
+		self.make_token_and_push(
+			TokenType::STRING(string_literal),
+			position_start,
+			self.position.copy(),
+		);
+
+		Ok(())
+	}
+
+	/// Lexes `r"..."` or `r#"..."#`, where nothing is escaped and the string
+	/// ends at a quote followed by as many `#` as opened it.
+	pub fn lexe_raw_string(&mut self) -> ResultLexer {
+		let position_start: Position = self.position.copy();
+		self.next_char(); // r
+
+		let mut hashes: usize = 0;
+		while self.cchar == '#' {
+			hashes += 1;
+			self.next_char();
+		}
+
+		if self.cchar != '"' {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected '\"' to open raw string"));
+			exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+			return Err(exception);
+		}
+		self.next_char(); // "
+
 		let mut string_literal: String = String::new();
-		let mut escape: bool = false;
-		self.next_char();
 
-		while !self.is_eof_char() && (self.cchar != '"' || escape) {
-			if escape {
+		loop {
+			if self.is_eof_char() {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::unexpected_eof("unterminated raw string"));
+				exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+				return Err(exception);
+			}
+
+			if self.cchar == '"' {
+				self.next_char(); // "
+
+				let mut closing: usize = 0;
+				while closing < hashes && self.cchar == '#' {
+					closing += 1;
+					self.next_char();
+				}
+
+				if closing == hashes {
+					break;
+				}
+
+				string_literal.push('"');
+				string_literal.extend(std::iter::repeat_n('#', closing));
+				continue;
+			}
+
+			string_literal.push(self.cchar);
+			self.next_char_in_text();
+		}
+
+		self.make_token_and_push(
+			TokenType::STRING(string_literal),
+			position_start,
+			self.position.copy(),
+		);
+
+		Ok(())
+	}
+
+	pub fn lexe_bytes(&mut self) -> ResultLexer {
+		let position_start: Position = self.position.copy();
+		let mut bytes_literal: Vec<u8> = Vec::new();
+		self.next_char(); // b
+		self.next_char(); // "
+
+		while !self.is_eof_char() && self.cchar != '"' {
+			if self.cchar == '\\' {
+				let escape_start: Position = self.position.copy();
+				self.next_char(); // \
+
 				match self.cchar {
-					'\\' => string_literal.push('\\'),
-					'"' => string_literal.push('\"'),
-					'n' => string_literal.push('\n'),
-					'r' => string_literal.push('\r'),
-					't' => string_literal.push('\t'),
-					c => {
+					'x' => bytes_literal.push(self.lexe_hex_escape(escape_start)?),
+					'u' => {
 						let mut exception: Exception = Exception::not_runtime(
-							Except::invalid_syntax(format!("unknown character escape: `{}`", c)),
+							Except::invalid_syntax("unicode escape in bytes literal"),
 						);
-						exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+						exception.push(ExceptionPoint::new(&self.module, escape_start));
 						return Err(exception);
 					},
+					_ => bytes_literal.push(self.lexe_escape(escape_start)? as u8),
 				}
-				escape = false
+			} else if self.cchar.is_ascii() {
+				bytes_literal.push(self.cchar as u8);
 			} else {
-				if self.cchar == '\\' {
-					escape = true;
-				} else {
-					string_literal.push(self.cchar);
-				}
+				let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+					"non-ASCII character in bytes literal, use a \\x escape",
+				));
+				exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
+				return Err(exception);
 			}
-			self.next_char()
+			self.next_char_in_text()
 		}
 
 		if self.cchar != '"' {
 			let mut exception: Exception =
-				Exception::not_runtime(Except::unexpected_eof("unterminated double quote string"));
+				Exception::not_runtime(Except::unexpected_eof("unterminated bytes literal"));
 			exception.push(ExceptionPoint::new(&self.module, self.position.copy()));
 			return Err(exception);
 		}
 
 		self.next_char();
 		self.make_token_and_push(
-			TokenType::STRING(string_literal),
+			TokenType::BYTES(bytes_literal),
 			position_start,
 			self.position.copy(),
 		);
 
 		Ok(())
 	}
+
+	/// Reads the escape whose `\` is at `escape_start`; `cchar` is the
+	/// character after the `\`, and is left on the escape's last character.
+	pub(crate) fn lexe_escape(&mut self, escape_start: Position) -> Result<char, Exception> {
+		match self.cchar {
+			'\\' => Ok('\\'),
+			'"' => Ok('"'),
+			'\'' => Ok('\''),
+			'n' => Ok('\n'),
+			'r' => Ok('\r'),
+			't' => Ok('\t'),
+			'0' => Ok('\0'),
+			'x' => match self.lexe_hex_escape(escape_start.copy())? {
+				byte if byte <= 0x7f => Ok(byte as char),
+				byte => Err(self.escape_err(
+					format!("out of range hex escape: \\x{:02x} (must be at most \\x7f)", byte),
+					escape_start,
+				)),
+			},
+			'u' => self.lexe_unicode_escape(escape_start),
+			c => Err(self.escape_err(format!("unknown character escape: `{}`", c), escape_start)),
+		}
+	}
+
+	/// Reads the two hex digits of a `\xNN` escape; `cchar` is the `x`.
+	pub(crate) fn lexe_hex_escape(&mut self, escape_start: Position) -> Result<u8, Exception> {
+		let mut digits: String = String::new();
+
+		for _ in 0..2 {
+			self.next_char();
+
+			if !self.cchar.is_ascii_hexdigit() {
+				return Err(
+					self.escape_err("\\x escape must be followed by two hex digits", escape_start)
+				);
+			}
+
+			digits.push(self.cchar);
+		}
+
+		Ok(u8::from_str_radix(&digits, 16).unwrap())
+	}
+
+	/// Reads a `\u{...}` escape of one to six hex digits; `cchar` is the `u`.
+	fn lexe_unicode_escape(&mut self, escape_start: Position) -> Result<char, Exception> {
+		self.next_char(); // u
+
+		if self.cchar != '{' {
+			return Err(self.escape_err("expected '{' after \\u", escape_start));
+		}
+
+		let mut digits: String = String::new();
+		self.next_char(); // {
+
+		while self.cchar.is_ascii_hexdigit() {
+			digits.push(self.cchar);
+			self.next_char();
+		}
+
+		if self.cchar != '}' || digits.is_empty() || digits.len() > 6 {
+			return Err(self.escape_err(
+				"\\u escape must be \\u{ followed by 1 to 6 hex digits and }",
+				escape_start,
+			));
+		}
+
+		let code_point: u32 = u32::from_str_radix(&digits, 16).unwrap();
+
+		match char::from_u32(code_point) {
+			Some(c) => Ok(c),
+			None if (0xd800..=0xdfff).contains(&code_point) => Err(self.escape_err(
+				format!("invalid unicode escape: {:X} is a surrogate", code_point),
+				escape_start,
+			)),
+			None => Err(self.escape_err(
+				format!("invalid unicode escape: {:X} is past 10FFFF", code_point),
+				escape_start,
+			)),
+		}
+	}
+
+	fn escape_err<T: Into<String>>(&self, message: T, escape_start: Position) -> Exception {
+		let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(message));
+		exception.push(ExceptionPoint::new(&self.module, escape_start));
+		exception
+	}
+}
+
+/// Turns the text of a triple quoted string into its value: the line break
+/// after the opening quotes and the blank line before the closing ones are
+/// dropped, and the indentation common to every later non-blank line is
+/// removed. Escaped characters never count as indentation.
+fn strip_indentation(text: Vec<(char, bool)>) -> String {
+	let mut lines: Vec<&[(char, bool)]> = text.split(|c| *c == ('\n', false)).collect();
+	let is_blank =
+		|line: &&[(char, bool)]| line.iter().all(|(c, escaped)| !escaped && c.is_whitespace());
+
+	// Text right after the opening quotes keeps its own spacing.
+	let opening: usize = match lines.len() > 1 && lines.first().is_some_and(is_blank) {
+		true => {
+			lines.remove(0);
+			0
+		},
+		false => 1,
+	};
+	if lines.len() > 1 && lines.last().is_some_and(is_blank) {
+		lines.pop();
+	}
+
+	let indentation: usize = lines
+		.iter()
+		.skip(opening)
+		.filter(|line| !is_blank(line))
+		.map(|line| {
+			line.iter().take_while(|(c, escaped)| !escaped && (*c == ' ' || *c == '\t')).count()
+		})
+		.min()
+		.unwrap_or(0);
+
+	lines
+		.iter()
+		.enumerate()
+		.map(|(i, line)| {
+			let strip: usize = if i < opening { 0 } else { indentation.min(line.len()) };
+			line.iter().skip(strip).map(|(c, _)| *c).collect::<String>()
+		})
+		.collect::<Vec<String>>()
+		.join("\n")
 }
@@ -10,18 +10,16 @@ impl Lexer {
 		while !self.is_eof_char() && self.cchar.is_whitespace() {
 			if self.cchar == '\n' {
 				self.position.column += 1;
-				self.make_token_and_push(
-					TokenType::NEWLINE,
-					position_start.copy(),
-					self.position.copy(),
-				);
+				let position_end: Position = self.position.copy();
 				self.next_char();
+				self.make_token_and_push(TokenType::NEWLINE, position_start.copy(), position_end);
 				self.position.column = 0;
 				self.position.line += 1;
 				continue;
 			}
 
 			self.next_char();
+			self.keep_trivia();
 			position_start = self.position.copy();
 		}
 
@@ -2,7 +2,10 @@
 
 use crate::preludes::*;
 
+mod lchar;
+mod lcomment;
 mod lidentifier;
+mod llabel;
 mod lnumber;
 mod lpunctuations;
 mod lstring;
@@ -18,10 +21,27 @@ pub struct Lexer {
 	tokens_cache: Vec<Token>,
 	source: Source,
 	module: String,
+	lossless: bool,
+	trivia: String,
+	text: String,
+	lines: Vec<String>,
+	line: String,
+	first_line: usize,
+	source_error: Option<Exception>,
 }
 
 impl Lexer {
 	pub fn new<T: Into<String>>(source: Source, module: T) -> Self {
+		Self::with_trivia(source, module, false)
+	}
+
+	/// Like `new`, but every token keeps its own text and the whitespace and
+	/// comments around it, so the tokens can be turned back into the source.
+	pub fn new_lossless<T: Into<String>>(source: Source, module: T) -> Self {
+		Self::with_trivia(source, module, true)
+	}
+
+	fn with_trivia<T: Into<String>>(source: Source, module: T, lossless: bool) -> Self {
 		let mut lexer: Self = Self {
 			cchar: '\0',
 			nchar: '\0',
@@ -30,28 +50,108 @@ impl Lexer {
 			source,
 			tokens_cache: Vec::new(),
 			module: module.into(),
+			lossless,
+			trivia: String::new(),
+			text: String::new(),
+			lines: Vec::new(),
+			line: String::new(),
+			first_line: 0,
+			source_error: None,
 		};
 		lexer.next_char(); // '\0'
 		lexer.next_char(); // '\0'
+
+		// A byte order mark takes no column of the first line.
+		if lexer.cchar == '\u{feff}' {
+			lexer.next_char();
+			lexer.position.column = 0;
+			lexer.line.clear();
+			lexer.keep_trivia();
+		}
+
+		if lexer.cchar == '#' && lexer.nchar == '!' {
+			lexer.lexe_shebang();
+		}
+
 		lexer
 	}
 
 	pub fn get_module(&self) -> String { self.module.clone() }
 
+	/// The source between two positions of the lines read so far, like the
+	/// text a statement was parsed from.
+	pub fn source_text(&self, start: &Position, end: &Position) -> String {
+		let mut text: String = String::new();
+
+		for line in start.line..=end.line {
+			let chars: &str = match line.checked_sub(self.first_line) {
+				Some(index) if index < self.lines.len() => &self.lines[index],
+				Some(index) if index == self.lines.len() => &self.line,
+				_ => "",
+			};
+			let from: usize = if line == start.line { start.column } else { 0 };
+			let to: usize = if line == end.line { end.column } else { usize::MAX };
+			text.extend(chars.chars().skip(from).take(to.saturating_sub(from)));
+
+			if line != end.line {
+				text.push('\n');
+			}
+		}
+
+		text
+	}
+
+	/// Drops the lines before `line`; `source_text` won't be asked for them.
+	pub fn forget_lines_before(&mut self, line: usize) {
+		let count: usize = line.saturating_sub(self.first_line).min(self.lines.len());
+		self.lines.drain(..count);
+		self.first_line += count;
+	}
+
 	fn is_eof_char(&self) -> bool { self.cchar == '\0' }
 
 	fn next_char(&mut self) {
 		if self.cchar != '\0' {
 			self.position.column += 1;
+
+			if self.lossless {
+				self.text.push(self.cchar);
+			}
+
+			match self.cchar {
+				'\n' => self.lines.push(std::mem::take(&mut self.line)),
+				c => self.line.push(c),
+			}
 		}
 
 		self.cchar = self.nchar;
 		self.nchar = match self.source.next_char() {
-			Some(c) => c,
-			None => '\0',
+			Ok(Some(c)) => c,
+			Ok(None) => '\0',
+			// The input stops at bytes that can't be read; `next` reports why
+			// once the lexer gets there.
+			Err(mut exception) => {
+				if self.source_error.is_none() {
+					exception.push(ExceptionPoint::new(&self.module, self.source.position()));
+					self.source_error = Some(exception);
+				}
+				'\0'
+			},
 		};
 	}
 
+	/// Like `next_char`, but keeps `position` on the right line when a
+	/// literal spans a newline.
+	fn next_char_in_text(&mut self) {
+		if self.cchar == '\n' {
+			self.next_char();
+			self.position.column = 0;
+			self.position.line += 1;
+		} else {
+			self.next_char();
+		}
+	}
+
 	fn push_token(&mut self, token: Token) {
 		if token.typer.is(TokenType::EOF) {
 			self.eof = true
@@ -63,7 +163,29 @@ impl Lexer {
 	fn make_token_and_push(
 		&mut self, typer: TokenType, position_start: Position, position_end: Position,
 	) {
-		self.push_token(Token::new(typer, TokenPosition::new(position_start, position_end)));
+		let mut token: Token = Token::new(typer, TokenPosition::new(position_start, position_end));
+
+		if self.lossless {
+			// Trivia after a token on its own line trails it; anything else
+			// leads the next token.
+			let trivia: String = std::mem::take(&mut self.trivia);
+			match self.tokens_cache.last_mut() {
+				Some(previous) if !previous.typer.is(TokenType::NEWLINE) =>
+					previous.trailing_trivia.push_str(&trivia),
+				_ => token.leading_trivia = trivia,
+			}
+			token.text = std::mem::take(&mut self.text);
+		}
+
+		self.push_token(token);
+	}
+
+	/// Marks what was read since the last token as trivia.
+	fn keep_trivia(&mut self) {
+		if self.lossless {
+			let text: String = std::mem::take(&mut self.text);
+			self.trivia.push_str(&text);
+		}
 	}
 
 	fn invalid_syntax_err(&self) -> ResultLexer {
@@ -76,22 +198,34 @@ impl Lexer {
 	}
 
 	pub fn next(&mut self) -> Result<Token, Exception> {
-		if self.tokens_cache.len() > 0 {
+		// A lossless lexer holds a token back until the next one is lexed, as
+		// the trivia in between may still trail it.
+		let ready: usize = if self.lossless { 2 } else { 1 };
+
+		if self.tokens_cache.len() >= ready || (self.eof && !self.tokens_cache.is_empty()) {
 			return Ok(self.tokens_cache.remove(0));
 		}
 
+		if let Some(exception) = self.source_error.as_ref().filter(|_| self.is_eof_char()) {
+			return Err(exception.clone());
+		}
+
 		if self.is_eof_char() {
 			self.make_token_and_push(TokenType::EOF, self.position.copy(), self.position.copy());
 			return self.next();
 		}
 
-		match self.cchar {
-			c if c.is_whitespace() => self.lexe_whitespace()?,
-			c if c.is_ascii_punctuation() => self.lexe_punctuations()?,
-			c if c.is_digit(10) => self.lexe_number()?,
-			c if c == '_' || c.is_alphabetic() => self.lexe_identifier_keyword()?,
-			_ => self.invalid_syntax_err()?,
+		let lexed: ResultLexer = match self.cchar {
+			c if c.is_whitespace() => self.lexe_whitespace(),
+			c if c == 'b' && self.nchar == '"' => self.lexe_bytes(),
+			c if c == 'r' && (self.nchar == '"' || self.nchar == '#') => self.lexe_raw_string(),
+			c if c == '_' || c.is_alphabetic() => self.lexe_identifier_keyword(),
+			c if c.is_ascii_punctuation() => self.lexe_punctuations(),
+			c if c.is_digit(10) => self.lexe_number(),
+			_ => self.invalid_syntax_err(),
 		};
+		// A literal cut short by unreadable input fails for that reason.
+		lexed.map_err(|exception| self.source_error.clone().unwrap_or(exception))?;
 
 		self.next()
 	}
@@ -100,12 +234,11 @@ impl Lexer {
 		let mut tokens: Vec<Token> = vec![];
 
 		loop {
-			tokens.push(self.next()?);
+			let token: Token = self.next()?;
+			let is_eof: bool = token.typer.is(TokenType::EOF);
+			tokens.push(token);
 
-			if self.is_eof_char() {
-				if !self.eof {
-					tokens.push(self.next()?);
-				}
+			if is_eof {
 				break;
 			}
 		}
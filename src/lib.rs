@@ -1,6 +1,7 @@
 // Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
 
 pub mod ast;
+pub mod checker;
 pub mod error;
 pub mod interpreter;
 pub mod lexer;
@@ -17,13 +18,14 @@ pub mod preludes {
 	pub type GLResult = Result<Object, Exception>;
 
 	pub use std::cell::RefCell;
-	pub use std::collections::HashMap;
+	pub use std::collections::{HashMap, HashSet};
 	pub use std::rc::Rc;
 
 	pub use num::bigint::ToBigInt;
 	pub use num::{BigInt, BigRational, FromPrimitive, ToPrimitive};
 
 	pub use crate::ast::*;
+	pub use crate::checker::*;
 	pub use crate::error::*;
 	pub use crate::interpreter::*;
 	pub use crate::lexer::*;
@@ -0,0 +1,465 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use std::time::{Duration, Instant};
+
+use crate::preludes::*;
+
+/// Polls the state of a future created by a native function. Returns
+/// `Some(value)` once the work is done and `None` while it is still pending.
+/// A pending native that knows when it can make progress should tell the
+/// event loop through `Interpreter::wake_at`, otherwise it is polled again
+/// on the next idle tick.
+pub type FnNativePoll = fn(&mut Interpreter, &mut Object) -> Result<Option<Object>, Exception>;
+
+pub struct GFuture {
+	name: String,
+	state: Rc<RefCell<FutureState>>,
+}
+
+struct FutureState {
+	kind: Option<FutureKind>,
+	status: FutureStatus,
+}
+
+enum FutureStatus {
+	Pending,
+	Ready(Box<Object>),
+	Failed(Exception),
+}
+
+enum FutureKind {
+	Coroutine(Box<Coroutine>),
+	Sleep { duration: Duration, deadline: Option<Instant> },
+	Gather(Vec<GFuture>),
+	Timeout { future: GFuture, duration: Duration, deadline: Option<Instant> },
+	Native { poll: FnNativePoll, data: Box<Object> },
+}
+
+struct Coroutine {
+	name: String,
+	returns: Option<String>,
+	body: Resumable,
+	awaiting: Awaiting,
+}
+
+/// A coroutine pauses at every `let` whose whole value is an `await`, until
+/// the awaited future is ready and its value bound to the name.
+struct Awaiting(Option<(GFuture, String)>);
+
+impl GFuture {
+	fn new<T: Into<String>>(name: T, kind: FutureKind) -> Self {
+		Self {
+			name: name.into(),
+			state: Rc::new(RefCell::new(FutureState {
+				kind: Some(kind),
+				status: FutureStatus::Pending,
+			})),
+		}
+	}
+
+	pub fn coroutine(
+		interpreter: &Interpreter, name: Option<String>, returns: Option<String>, body: Block,
+		scope: Scope,
+	) -> Self {
+		let name: String = match name {
+			Some(name) => name,
+			None => String::from("<anonymous>"),
+		};
+
+		Self::new(
+			name.clone(),
+			FutureKind::Coroutine(Box::new(Coroutine {
+				name,
+				returns,
+				body: Resumable::new(interpreter, lower_awaits(&body), scope),
+				awaiting: Awaiting(None),
+			})),
+		)
+	}
+
+	pub fn sleep(duration: Duration) -> Self {
+		Self::new("sleep", FutureKind::Sleep { duration, deadline: None })
+	}
+
+	pub fn gather(futures: Vec<GFuture>) -> Self {
+		Self::new("gather", FutureKind::Gather(futures))
+	}
+
+	pub fn timeout(future: GFuture, duration: Duration) -> Self {
+		Self::new("timeout", FutureKind::Timeout { future, duration, deadline: None })
+	}
+
+	pub fn native<T: Into<String>>(name: T, poll: FnNativePoll, data: Object) -> Self {
+		Self::new(name, FutureKind::Native { poll, data: Box::new(data) })
+	}
+
+	pub fn get_name(&self) -> String { self.name.clone() }
+
+	pub fn is_done(&self) -> bool { !matches!(self.state.borrow().status, FutureStatus::Pending) }
+
+	/// Drives the future one step. Returns `Some(value)` once it has
+	/// completed; a finished future keeps returning its value or exception.
+	pub fn poll(&self, interpreter: &mut Interpreter) -> Result<Option<Object>, Exception> {
+		let mut kind: FutureKind = {
+			let mut state = self.state.borrow_mut();
+
+			match &state.status {
+				FutureStatus::Ready(object) => return Ok(Some(object.as_ref().clone())),
+				FutureStatus::Failed(exception) => return Err(exception.clone()),
+				FutureStatus::Pending => {},
+			}
+
+			match state.kind.take() {
+				Some(kind) => kind,
+				None => {
+					let mut exception: Exception = Exception::in_runtime(Except::error(format!(
+						"future '{}' is already being awaited",
+						self.name
+					)));
+					exception.push(ExceptionPoint::new(self.get_name(), Position::default()));
+					return Err(exception);
+				},
+			}
+		};
+
+		let result: Result<Option<Object>, Exception> = match &mut kind {
+			FutureKind::Coroutine(coroutine) => coroutine.poll(interpreter),
+			FutureKind::Sleep { duration, deadline } => {
+				let deadline: Instant = *deadline.get_or_insert_with(|| Instant::now() + *duration);
+
+				if Instant::now() >= deadline {
+					Ok(Some(Object::Null))
+				} else {
+					interpreter.wake_at(deadline);
+					Ok(None)
+				}
+			},
+			FutureKind::Gather(futures) => {
+				let mut values: Vec<Object> = Vec::new();
+
+				for future in futures.iter() {
+					if let Some(value) = future.poll(interpreter)? {
+						values.push(value);
+					}
+				}
+
+				if values.len() == futures.len() {
+					Ok(Some(Object::Vec(values)))
+				} else {
+					Ok(None)
+				}
+			},
+			FutureKind::Timeout { future, duration, deadline } => {
+				let deadline: Instant = *deadline.get_or_insert_with(|| Instant::now() + *duration);
+
+				match future.poll(interpreter)? {
+					Some(value) => Ok(Some(value)),
+					None if Instant::now() >= deadline => {
+						let mut exception: Exception =
+							Exception::in_runtime(Except::timeout(format!(
+								"'{}' did not complete in {}ms",
+								future.name,
+								duration.as_millis()
+							)));
+						exception.push(ExceptionPoint::new(self.get_name(), Position::default()));
+						Err(exception)
+					},
+					None => {
+						interpreter.wake_at(deadline);
+						Ok(None)
+					},
+				}
+			},
+			FutureKind::Native { poll, data } => poll(interpreter, data.as_mut()),
+		};
+
+		let mut state = self.state.borrow_mut();
+		state.kind = Some(kind);
+		match &result {
+			Ok(Some(object)) => state.status = FutureStatus::Ready(Box::new(object.clone())),
+			Err(exception) => state.status = FutureStatus::Failed(exception.clone()),
+			Ok(None) => {},
+		}
+
+		result
+	}
+}
+
+impl Coroutine {
+	fn poll(&mut self, interpreter: &mut Interpreter) -> Result<Option<Object>, Exception> {
+		match self.body.resume(interpreter, &mut self.awaiting)? {
+			Some(()) => Ok(None),
+			None => {
+				let result: Object = self.body.result();
+				check_type(&self.returns, &result, || format!("return value of {}()", self.name))?;
+				Ok(Some(result))
+			},
+		}
+	}
+}
+
+impl Suspend for Awaiting {
+	type Value = ();
+
+	fn pauses(statement: &Statement) -> bool {
+		matches!(statement, Statement::Let(_, None, Expression::Await(_)))
+	}
+
+	fn pause(
+		&mut self, interpreter: &mut Interpreter, statement: &Statement,
+	) -> Result<Option<()>, Exception> {
+		if let Statement::Let(name, None, Expression::Await(expression)) = statement {
+			match expression.eval(interpreter)? {
+				Object::Future(future) => self.0 = Some((future, name.clone())),
+				o => return Err(not_awaitable(&o)),
+			}
+		}
+
+		self.resume(interpreter)
+	}
+
+	fn resume(&mut self, interpreter: &mut Interpreter) -> Result<Option<()>, Exception> {
+		let value: Object = match &self.0 {
+			Some((future, _)) => match future.poll(interpreter) {
+				Ok(Some(value)) => value,
+				Ok(None) => return Ok(Some(())),
+				Err(exception) => {
+					self.0 = None;
+					return Err(exception);
+				},
+			},
+			None => return Ok(None),
+		};
+
+		if let Some((_, name)) = self.0.take() {
+			interpreter.set(name, value);
+		}
+
+		Ok(None)
+	}
+}
+
+pub(crate) fn not_awaitable(object: &Object) -> Exception {
+	let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+		"object '{}' can't be used in 'await' expression",
+		object.typer()
+	)));
+	exception.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+	exception
+}
+
+impl Clone for GFuture {
+	fn clone(&self) -> Self { Self { name: self.name.clone(), state: self.state.clone() } }
+}
+
+impl std::fmt::Display for GFuture {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "<future {}>", self.name)
+	}
+}
+
+impl PartialEq for GFuture {
+	fn eq(&self, other: &Self) -> bool { Rc::ptr_eq(&self.state, &other.state) }
+}
+
+impl Eq for GFuture {}
+
+// Await lowering
+
+/// Rewrites an async body so that every `await` is the whole value of a
+/// `let` of its own, where the coroutine can pause. Operands evaluated
+/// before an `await` are saved to temporaries first, so evaluation still
+/// runs left to right.
+fn lower_awaits(body: &Block) -> Block { Lowering { temporaries: 0 }.block(body) }
+
+struct Lowering {
+	temporaries: usize,
+}
+
+impl Lowering {
+	fn block(&mut self, block: &Block) -> Block {
+		let mut statements: Vec<Statement> = Vec::new();
+
+		for statement in block.0.iter() {
+			let statement: Statement = self.statement(statement, &mut statements);
+			statements.push(statement);
+		}
+
+		Block::new(statements)
+	}
+
+	/// Lowers `statement`, pushing what must run before it onto `before`.
+	fn statement(&mut self, statement: &Statement, before: &mut Vec<Statement>) -> Statement {
+		match statement {
+			Statement::Let(name, typer, value) =>
+				Statement::Let(name.clone(), typer.clone(), self.expression(value, before)),
+			Statement::LetAlter(name, value) =>
+				Statement::LetAlter(name.clone(), self.expression(value, before)),
+			Statement::Expression(expression) =>
+				Statement::Expression(self.expression(expression, before)),
+			Statement::ExpressionReturn(expression) =>
+				Statement::ExpressionReturn(self.expression(expression, before)),
+			Statement::Yield(expression) => Statement::Yield(self.expression(expression, before)),
+			Statement::Assert(condition, message, source) => Statement::Assert(
+				self.expression(condition, before),
+				message.clone(),
+				source.clone(),
+			),
+			Statement::Export(statement) =>
+				Statement::Export(Box::new(self.statement(statement, before))),
+			Statement::Doc(doc, statement) =>
+				Statement::Doc(doc.clone(), Box::new(self.statement(statement, before))),
+			Statement::With { value, name, body } => Statement::With {
+				value: self.expression(value, before),
+				name: name.clone(),
+				body: self.block(body),
+			},
+			Statement::For { label, targets, iter, body } => Statement::For {
+				label: label.clone(),
+				targets: targets.clone(),
+				iter: self.expression(iter, before),
+				body: self.block(body),
+			},
+			statement => statement.clone(),
+		}
+	}
+
+	fn expression(&mut self, expression: &Expression, before: &mut Vec<Statement>) -> Expression {
+		if !expression.contains_await() {
+			return expression.clone();
+		}
+
+		match expression {
+			Expression::Await(operand) => {
+				let operand: Expression = self.expression(operand, before);
+				let name: String = self.temporary();
+				before.push(Statement::Let(
+					name.clone(),
+					None,
+					Expression::Await(Box::new(operand)),
+				));
+				Expression::Identifier(name)
+			},
+			Expression::Prefix(prefix, right) =>
+				Expression::Prefix(prefix.clone(), Box::new(self.expression(right, before))),
+			Expression::Infix(infix, left, right) => {
+				let mut operands = self.operands(&[left, right], before).into_iter();
+				Expression::Infix(
+					infix.clone(),
+					Box::new(operands.next().unwrap()),
+					Box::new(operands.next().unwrap()),
+				)
+			},
+			Expression::Index(left, index) => {
+				let mut operands = self.operands(&[left, index], before).into_iter();
+				Expression::Index(
+					Box::new(operands.next().unwrap()),
+					Box::new(operands.next().unwrap()),
+				)
+			},
+			Expression::Slice(left, start, end) => {
+				let mut operands: Vec<&Expression> = vec![left];
+				operands.extend(start.iter().chain(end.iter()).map(|bound| bound.as_ref()));
+				let mut operands = self.operands(&operands, before).into_iter();
+				Expression::Slice(
+					Box::new(operands.next().unwrap()),
+					start.as_ref().map(|_| Box::new(operands.next().unwrap())),
+					end.as_ref().map(|_| Box::new(operands.next().unwrap())),
+				)
+			},
+			Expression::Call { function, arguments } => {
+				let mut operands: Vec<&Expression> = vec![function];
+				operands.extend(arguments.iter());
+				let mut operands = self.operands(&operands, before).into_iter();
+				Expression::Call {
+					function: Box::new(operands.next().unwrap()),
+					arguments: operands.collect(),
+				}
+			},
+			// The right side names what to take or call, only the arguments
+			// of a call are evaluated.
+			Expression::Property(from, property) => match property.as_ref() {
+				Expression::Call { function, arguments } => {
+					let mut operands: Vec<&Expression> = vec![from];
+					operands.extend(arguments.iter());
+					let mut operands = self.operands(&operands, before).into_iter();
+					Expression::Property(
+						Box::new(operands.next().unwrap()),
+						Box::new(Expression::Call {
+							function: function.clone(),
+							arguments: operands.collect(),
+						}),
+					)
+				},
+				property => Expression::Property(
+					Box::new(self.expression(from, before)),
+					Box::new(property.clone()),
+				),
+			},
+			Expression::Literal(literal) => Expression::Literal(match literal {
+				Literal::Vec(values) =>
+					Literal::Vec(self.operands(&values.iter().collect::<Vec<_>>(), before)),
+				Literal::Tuple(values) =>
+					Literal::Tuple(self.operands(&values.iter().collect::<Vec<_>>(), before)),
+				Literal::Set(values) =>
+					Literal::Set(self.operands(&values.iter().collect::<Vec<_>>(), before)),
+				Literal::HashMap(entries) => {
+					let operands: Vec<&Expression> =
+						entries.iter().flat_map(|(key, value)| [key, value]).collect();
+					let mut operands = self.operands(&operands, before).into_iter();
+					Literal::HashMap(
+						entries
+							.iter()
+							.map(|_| (operands.next().unwrap(), operands.next().unwrap()))
+							.collect(),
+					)
+				},
+				// The parser rejects an `await` in a comprehension of an
+				// async function.
+				literal => literal.clone(),
+			}),
+			expression => expression.clone(),
+		}
+	}
+
+	/// Lowers operands that are evaluated left to right. Those before the
+	/// last one holding an `await` are saved to temporaries, so they are
+	/// still evaluated before that `await` runs.
+	fn operands(
+		&mut self, operands: &[&Expression], before: &mut Vec<Statement>,
+	) -> Vec<Expression> {
+		let last: usize =
+			operands.iter().rposition(|operand| operand.contains_await()).unwrap_or(0);
+		let mut lowered: Vec<Expression> = Vec::new();
+
+		for (index, operand) in operands.iter().enumerate() {
+			let operand: Expression = self.expression(operand, before);
+
+			lowered.push(match operand {
+				Expression::Literal(
+					Literal::Null
+					| Literal::Integer(_)
+					| Literal::Float(_)
+					| Literal::Boolean(_)
+					| Literal::String(_)
+					| Literal::Char(_)
+					| Literal::Bytes(_),
+				) => operand,
+				operand if index < last => {
+					let name: String = self.temporary();
+					before.push(Statement::Let(name.clone(), None, operand));
+					Expression::Identifier(name)
+				},
+				operand => operand,
+			});
+		}
+
+		lowered
+	}
+
+	/// A name no identifier of the source can clash with.
+	fn temporary(&mut self) -> String {
+		self.temporaries += 1;
+		format!("${}", self.temporaries)
+	}
+}
@@ -0,0 +1,116 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+pub struct GGenerator {
+	name: Option<String>,
+	state: Rc<RefCell<GeneratorState>>,
+}
+
+struct GeneratorState {
+	/// Taken out while the body runs.
+	body: Option<Resumable>,
+}
+
+/// A generator pauses at every `yield`, handing out its value.
+struct Yield;
+
+impl GGenerator {
+	pub fn new(interpreter: &Interpreter, name: Option<String>, body: Block, scope: Scope) -> Self {
+		Self {
+			name,
+			state: Rc::new(RefCell::new(GeneratorState {
+				body: Some(Resumable::new(interpreter, body, scope)),
+			})),
+		}
+	}
+
+	pub fn get_name(&self) -> String {
+		match &self.name {
+			Some(name) => name.clone(),
+			None => String::from("<anonymous>"),
+		}
+	}
+
+	pub fn is_finished(&self) -> bool {
+		self.state.borrow().body.as_ref().is_some_and(Resumable::is_finished)
+	}
+
+	/// Runs the body from where the last `yield` left it until the next
+	/// `yield`, which may be inside a `for` or `with` block. Returns `None`
+	/// once the body has run to the end.
+	pub fn resume(&self, interpreter: &mut Interpreter) -> Result<Option<Object>, Exception> {
+		let mut body: Resumable = {
+			let mut state = self.state.borrow_mut();
+
+			let body: Resumable = match state.body.take() {
+				Some(body) => body,
+				None => {
+					let mut exception: Exception = Exception::in_runtime(Except::error(format!(
+						"generator '{}' already executing",
+						self.get_name()
+					)));
+					exception.push(ExceptionPoint::new(self.get_name(), Position::default()));
+					return Err(exception);
+				},
+			};
+
+			if body.is_finished() {
+				state.body = Some(body);
+				return Ok(None);
+			}
+
+			body
+		};
+
+		let result: Result<Option<Object>, Exception> = body.resume(interpreter, &mut Yield);
+
+		self.state.borrow_mut().body = Some(body);
+		result
+	}
+
+	pub fn contains(
+		&self, interpreter: &mut Interpreter, item: &Object,
+	) -> Result<bool, Exception> {
+		while let Some(object) = self.resume(interpreter)? {
+			if &object == item {
+				return Ok(true);
+			}
+		}
+
+		Ok(false)
+	}
+}
+
+impl Suspend for Yield {
+	type Value = Object;
+
+	fn pauses(statement: &Statement) -> bool { matches!(statement, Statement::Yield(_)) }
+
+	fn pause(
+		&mut self, interpreter: &mut Interpreter, statement: &Statement,
+	) -> Result<Option<Object>, Exception> {
+		match statement {
+			Statement::Yield(expression) => expression.eval(interpreter).map(Some),
+			_ => Ok(None),
+		}
+	}
+
+	fn resume(&mut self, _: &mut Interpreter) -> Result<Option<Object>, Exception> { Ok(None) }
+}
+
+impl Clone for GGenerator {
+	fn clone(&self) -> Self { Self { name: self.name.clone(), state: self.state.clone() } }
+}
+
+impl std::fmt::Display for GGenerator {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "<generator object {}>", self.get_name())
+	}
+}
+
+impl PartialEq for GGenerator {
+	fn eq(&self, other: &Self) -> bool { Rc::ptr_eq(&self.state, &other.state) }
+}
+
+impl Eq for GGenerator {}
@@ -0,0 +1,60 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+/// Walks the values of any iterable object: the items of a Vec, Tuple or
+/// Set, the keys of a HashMap, the characters of a String, the bytes of a
+/// Bytes, or the values a Generator yields.
+pub enum GIterator {
+	Values(std::vec::IntoIter<Object>),
+	Generator(GGenerator),
+}
+
+impl GIterator {
+	pub fn new(object: &Object) -> Result<Self, Exception> {
+		Ok(match object {
+			Object::Vec(vector) => Self::Values(vector.clone().into_iter()),
+			Object::Tuple(values) => Self::Values(values.clone().into_iter()),
+			Object::Set(set) =>
+				Self::Values(set.iter().cloned().collect::<Vec<Object>>().into_iter()),
+			Object::HashMap(hashmap) =>
+				Self::Values(hashmap.keys().cloned().collect::<Vec<Object>>().into_iter()),
+			Object::String(string) =>
+				Self::Values(string.chars().map(Object::Char).collect::<Vec<Object>>().into_iter()),
+			Object::Bytes(bytes) => Self::Values(
+				bytes
+					.iter()
+					.map(|byte| Object::Integer(byte.to_bigint().unwrap()))
+					.collect::<Vec<Object>>()
+					.into_iter(),
+			),
+			Object::Generator(generator) => Self::Generator(generator.clone()),
+			o => {
+				let mut exception: Exception = Exception::in_runtime(Except::type_(format!(
+					"'{}' object is not iterable",
+					o.typer()
+				)));
+				exception
+					.push(ExceptionPoint::new("self.module_context.clone()", Position::default()));
+				return Err(exception);
+			},
+		})
+	}
+
+	pub fn next(&mut self, interpreter: &mut Interpreter) -> Result<Option<Object>, Exception> {
+		match self {
+			Self::Values(values) => Ok(values.next()),
+			Self::Generator(generator) => generator.resume(interpreter),
+		}
+	}
+
+	pub fn collect(mut self, interpreter: &mut Interpreter) -> Result<Vec<Object>, Exception> {
+		let mut values: Vec<Object> = Vec::new();
+
+		while let Some(object) = self.next(interpreter)? {
+			values.push(object);
+		}
+
+		Ok(values)
+	}
+}
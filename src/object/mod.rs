@@ -1,10 +1,18 @@
 // Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
 
 mod dynlibrary;
+mod future;
+mod generator;
+mod iterator;
 mod ops;
+mod resumable;
 mod structrust;
 mod types;
 
 pub use dynlibrary::*;
+pub use future::*;
+pub use generator::*;
+pub use iterator::*;
+pub(crate) use resumable::*;
 pub use structrust::*;
 pub use types::*;
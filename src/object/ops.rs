@@ -1,7 +1,7 @@
 // Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
 
 use core::cmp::PartialOrd;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Sub};
 
 use crate::preludes::*;
 
@@ -70,6 +70,12 @@ impl Add for Object {
 				} else {
 					Err(unsupported_operand_type("+", self.typer(), other.typer()))
 				},
+			Object::Bytes(lhs) =>
+				if let Object::Bytes(rhs) = other {
+					Ok(Object::Bytes([lhs.as_slice(), rhs.as_slice()].concat()))
+				} else {
+					Err(unsupported_operand_type("+", self.typer(), other.typer()))
+				},
 			_ => Err(unsupported_operand_type("+", self.typer(), other.typer())),
 		}
 	}
@@ -134,6 +140,12 @@ impl Sub for Object {
 				} else {
 					Err(unsupported_operand_type("-", self.typer(), other.typer()))
 				},
+			Object::Set(lhs) =>
+				if let Object::Set(rhs) = other {
+					Ok(Object::Set(lhs.difference(&rhs).cloned().collect()))
+				} else {
+					Err(unsupported_operand_type("-", self.typer(), other.typer()))
+				},
 			_ => Err(unsupported_operand_type("-", self.typer(), other.typer())),
 		}
 	}
@@ -280,6 +292,42 @@ impl Div for Object {
 	}
 }
 
+impl BitOr for Object {
+	type Output = Result<Self, Exception>;
+
+	fn bitor(self, other: Self) -> Result<Self, Exception> {
+		match (&self, &other) {
+			(Object::Set(lhs), Object::Set(rhs)) =>
+				Ok(Object::Set(lhs.union(rhs).cloned().collect())),
+			_ => Err(unsupported_operand_type("|", self.typer(), other.typer())),
+		}
+	}
+}
+
+impl BitAnd for Object {
+	type Output = Result<Self, Exception>;
+
+	fn bitand(self, other: Self) -> Result<Self, Exception> {
+		match (&self, &other) {
+			(Object::Set(lhs), Object::Set(rhs)) =>
+				Ok(Object::Set(lhs.intersection(rhs).cloned().collect())),
+			_ => Err(unsupported_operand_type("&", self.typer(), other.typer())),
+		}
+	}
+}
+
+impl BitXor for Object {
+	type Output = Result<Self, Exception>;
+
+	fn bitxor(self, other: Self) -> Result<Self, Exception> {
+		match (&self, &other) {
+			(Object::Set(lhs), Object::Set(rhs)) =>
+				Ok(Object::Set(lhs.symmetric_difference(rhs).cloned().collect())),
+			_ => Err(unsupported_operand_type("^", self.typer(), other.typer())),
+		}
+	}
+}
+
 impl PartialOrd for Object {
 	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
 		match self {
@@ -329,11 +377,57 @@ impl PartialOrd for Object {
 				} else {
 					None
 				},
+			Object::Char(lhs) =>
+				if let Object::Char(rhs) = other {
+					Some(lhs.cmp(rhs))
+				} else {
+					None
+				},
 			_ => None,
 		}
 	}
 }
 
+impl Object {
+	pub fn contains(&self, item: &Object) -> Result<bool, Exception> {
+		match self {
+			Object::Vec(vector) => Ok(vector.contains(item)),
+			Object::Tuple(values) => Ok(values.contains(item)),
+			Object::HashMap(hashmap) => Ok(hashmap.contains_key(item)),
+			Object::Set(set) => Ok(set.contains(item)),
+			Object::String(string) =>
+				if let Object::String(substring) = item {
+					Ok(string.contains(substring.as_str()))
+				} else if let Object::Char(c) = item {
+					Ok(string.contains(*c))
+				} else {
+					Err(Exception::in_runtime(Except::type_(format!(
+						"'in <String>' requires String or Char as left operand, not {}",
+						item.typer()
+					))))
+				},
+			Object::Bytes(bytes) => match item {
+				Object::Integer(integer) => Ok(integer.to_u8().is_some_and(|b| bytes.contains(&b))),
+				Object::Bytes(subbytes) =>
+					Ok(subbytes.is_empty() || bytes.windows(subbytes.len()).any(|w| w == subbytes)),
+				o => Err(Exception::in_runtime(Except::type_(format!(
+					"'in <Bytes>' requires Integer or Bytes as left operand, not {}",
+					o.typer()
+				)))),
+			},
+			Object::StructRust(s) => match s.o.contains(item) {
+				Some(result) => Ok(result),
+				None => Err(not_iterable(self.typer())),
+			},
+			_ => Err(not_iterable(self.typer())),
+		}
+	}
+}
+
+fn not_iterable(typer: &str) -> Exception {
+	Exception::in_runtime(Except::type_(format!("argument of type '{}' is not iterable", typer)))
+}
+
 fn unsupported_operand_type(infixtype: &str, lhstype: &str, rhstype: &str) -> Exception {
 	Exception::in_runtime(Except::type_(format!(
 		"unsupported operand type(s) for {}: '{}' and '{}'",
@@ -0,0 +1,326 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use std::rc::Weak;
+
+use crate::preludes::*;
+
+/// Bodies dropped while paused, waiting for the interpreter to run the
+/// defers and `with` exits they still owe.
+pub(crate) type Abandoned = Rc<RefCell<Vec<Resumable>>>;
+
+/// What makes a body pause: `yield` for generators, `await` for coroutines.
+pub(crate) trait Suspend {
+	type Value;
+
+	/// Whether `statement` may pause the body. Such statements run through
+	/// `pause` instead of `Statement::eval`.
+	fn pauses(statement: &Statement) -> bool;
+
+	/// Runs a statement `pauses` accepted. Returns the value the body pauses
+	/// with, or `None` when it goes on without pausing.
+	fn pause(
+		&mut self, interpreter: &mut Interpreter, statement: &Statement,
+	) -> Result<Option<Self::Value>, Exception>;
+
+	/// Called first on every resume; returning a value pauses the body again
+	/// before its next statement runs.
+	fn resume(&mut self, interpreter: &mut Interpreter) -> Result<Option<Self::Value>, Exception>;
+}
+
+/// A function body that can pause and later pick up where it stopped, even
+/// from inside a `for` or `with` block. Every block, loop and `with` it is
+/// in the middle of keeps a frame.
+pub(crate) struct Resumable {
+	scope: Scope,
+	frames: Vec<Frame>,
+	result: Object,
+	abandoned: Weak<RefCell<Vec<Resumable>>>,
+}
+
+enum Frame {
+	/// A loop body keeps the defers collected before it, as each pass
+	/// defers to its own end.
+	Block {
+		block: Rc<Block>,
+		next: usize,
+		result: Object,
+		defers: Option<Vec<Expression>>,
+	},
+	For {
+		label: Option<String>,
+		targets: Vec<String>,
+		iterator: GIterator,
+		body: Rc<Block>,
+	},
+	With {
+		value: Expression,
+		object: Box<Object>,
+		scope: Option<Scope>,
+	},
+}
+
+impl Resumable {
+	pub(crate) fn new(interpreter: &Interpreter, body: Block, scope: Scope) -> Self {
+		Self {
+			scope,
+			frames: vec![Frame::Block {
+				block: Rc::new(body),
+				next: 0,
+				result: Object::Null,
+				defers: None,
+			}],
+			result: Object::Null,
+			abandoned: Rc::downgrade(&interpreter.abandoned),
+		}
+	}
+
+	pub(crate) fn is_finished(&self) -> bool { self.frames.is_empty() }
+
+	/// The value of the last statement of a finished body.
+	pub(crate) fn result(&self) -> Object { self.result.clone() }
+
+	/// Runs the body until it pauses, returning what it paused with, or
+	/// until it ends, returning `None`. Once it ends, by running out of
+	/// statements or by an exception, the pending defers have run.
+	pub(crate) fn resume<S: Suspend>(
+		&mut self, interpreter: &mut Interpreter, suspend: &mut S,
+	) -> Result<Option<S::Value>, Exception> {
+		self.enter(interpreter);
+
+		let result: Result<(), Exception> = match self.run(interpreter, suspend) {
+			Ok(Some(value)) => {
+				self.leave(interpreter);
+				return Ok(Some(value));
+			},
+			Ok(None) => Ok(()),
+			Err(exception) => self.unwind(interpreter, Err(exception)).map(|_| ()),
+		};
+		let result: Result<(), Exception> = interpreter.run_defers(result);
+
+		self.scope = interpreter.pop_scope();
+		result.map(|_| None)
+	}
+
+	/// Runs what a body dropped while paused still owes: the exits of the
+	/// `with` blocks it is in and every pending defer.
+	pub(crate) fn close(&mut self, interpreter: &mut Interpreter) -> Result<(), Exception> {
+		self.enter(interpreter);
+
+		let result: GLResult = self.unwind(interpreter, Ok(Object::Null));
+		let result: Result<(), Exception> = interpreter.run_defers(result).map(|_| ());
+
+		self.scope = interpreter.pop_scope();
+		result
+	}
+
+	fn enter(&mut self, interpreter: &mut Interpreter) {
+		interpreter.push_scope(std::mem::replace(&mut self.scope, Scope::new()));
+
+		for frame in self.frames.iter_mut() {
+			if let Frame::With { scope: scope @ Some(_), .. } = frame {
+				interpreter.push_scope(scope.take().unwrap());
+			}
+		}
+	}
+
+	fn leave(&mut self, interpreter: &mut Interpreter) {
+		for frame in self.frames.iter_mut().rev() {
+			if let Frame::With { scope, .. } = frame {
+				*scope = Some(interpreter.pop_scope());
+			}
+		}
+
+		self.scope = interpreter.pop_scope();
+	}
+
+	fn run<S: Suspend>(
+		&mut self, interpreter: &mut Interpreter, suspend: &mut S,
+	) -> Result<Option<S::Value>, Exception> {
+		if let Some(value) = suspend.resume(interpreter)? {
+			return Ok(Some(value));
+		}
+
+		loop {
+			let (block, index): (Rc<Block>, usize) = match self.frames.last_mut() {
+				Some(Frame::Block { block, next, .. })
+					if *next < block.0.len() && interpreter.control.is_none() =>
+				{
+					*next += 1;
+					(block.clone(), *next - 1)
+				},
+				Some(Frame::Block { .. }) => {
+					let result: Object = match self.frames.pop() {
+						Some(Frame::Block { result, defers: Some(defers), .. }) =>
+							interpreter.end_defers(defers, Ok(result))?,
+						Some(Frame::Block { result, .. }) => result,
+						_ => Object::Null,
+					};
+
+					match self.finish(interpreter, result)? {
+						Some(result) => {
+							self.result = result;
+							return Ok(None);
+						},
+						None => continue,
+					}
+				},
+				Some(Frame::For { .. }) => {
+					self.next_iteration(interpreter)?;
+					continue;
+				},
+				Some(Frame::With { .. }) | None => return Ok(None),
+			};
+
+			let statement: &Statement = &block.0[index];
+
+			match statement {
+				statement if S::pauses(statement) => {
+					self.set_result(Object::Null);
+
+					if let Some(value) = suspend.pause(interpreter, statement)? {
+						return Ok(Some(value));
+					}
+				},
+				Statement::For { label, targets, iter, body } if body.contains(S::pauses) => {
+					let iterator: GIterator = GIterator::new(&iter.eval(interpreter)?)?;
+					self.frames.push(Frame::For {
+						label: label.clone(),
+						targets: targets.clone(),
+						iterator,
+						body: Rc::new(body.clone()),
+					});
+				},
+				Statement::With { value, name, body } if body.contains(S::pauses) => {
+					let object: Object = enter_with(interpreter, value, name)?;
+					self.frames.push(Frame::With {
+						value: value.clone(),
+						object: Box::new(object),
+						scope: None,
+					});
+					self.frames.push(Frame::Block {
+						block: Rc::new(body.clone()),
+						next: 0,
+						result: Object::Null,
+						defers: None,
+					});
+				},
+				statement => {
+					let result: Object = statement.eval(interpreter)?;
+					self.set_result(result);
+				},
+			}
+		}
+	}
+
+	/// Hands the value of a finished block to the frame below it. Returns
+	/// the value of the whole body once its outermost block is done.
+	fn finish(
+		&mut self, interpreter: &mut Interpreter, result: Object,
+	) -> Result<Option<Object>, Exception> {
+		match self.frames.last() {
+			None => Ok(Some(result)),
+			Some(Frame::Block { .. }) => {
+				self.set_result(result);
+				Ok(None)
+			},
+			Some(Frame::For { .. }) => Ok(None),
+			Some(Frame::With { .. }) => {
+				let result: Object = match self.frames.pop() {
+					Some(Frame::With { value, object, .. }) =>
+						exit_with(interpreter, &value, &object, Ok(result))?,
+					_ => result,
+				};
+				self.finish(interpreter, result)
+			},
+		}
+	}
+
+	/// Starts the next pass of the innermost loop, or ends the loop once its
+	/// values run out or a `break` reaches it.
+	fn next_iteration(&mut self, interpreter: &mut Interpreter) -> Result<(), Exception> {
+		let (label, targets, iterator, body) = match self.frames.last_mut() {
+			Some(Frame::For { label, targets, iterator, body }) => (label, targets, iterator, body),
+			_ => return Ok(()),
+		};
+
+		let next: Option<Object> = match interpreter.control.take() {
+			None => iterator.next(interpreter)?,
+			Some(Control::Continue(target)) if target.is_none() || &target == label =>
+				iterator.next(interpreter)?,
+			Some(Control::Break(target)) if target.is_none() || &target == label => None,
+			control => {
+				interpreter.control = control;
+				None
+			},
+		};
+
+		match next {
+			Some(value) => {
+				bind_targets(interpreter, targets, value)?;
+				let body: Rc<Block> = body.clone();
+				let defers: Vec<Expression> = interpreter.begin_defers();
+				self.frames.push(Frame::Block {
+					block: body,
+					next: 0,
+					result: Object::Null,
+					defers: Some(defers),
+				});
+			},
+			None => {
+				self.frames.pop();
+				self.set_result(Object::Null);
+			},
+		}
+
+		Ok(())
+	}
+
+	fn set_result(&mut self, object: Object) {
+		if let Some(Frame::Block { result, .. }) = self.frames.last_mut() {
+			*result = object;
+		}
+	}
+
+	/// Leaves every frame, running the defers of loop bodies and giving each
+	/// `with` block `result` on exit. The first exception raised on the way
+	/// wins over later ones.
+	fn unwind(&mut self, interpreter: &mut Interpreter, result: GLResult) -> GLResult {
+		let mut result: GLResult = result;
+
+		while let Some(frame) = self.frames.pop() {
+			result = match frame {
+				Frame::Block { defers: Some(defers), .. } => interpreter.end_defers(defers, result),
+				Frame::With { value, object, .. } =>
+					match exit_with(interpreter, &value, &object, result.clone()) {
+						Err(exception) if result.is_ok() => Err(exception),
+						_ => result,
+					},
+				_ => result,
+			};
+		}
+
+		result
+	}
+}
+
+impl Drop for Resumable {
+	fn drop(&mut self) {
+		let owes_cleanup: bool = !self.scope.defers.is_empty()
+			|| self.frames.iter().any(|frame| match frame {
+				Frame::Block { defers: Some(defers), .. } => !defers.is_empty(),
+				Frame::With { .. } => true,
+				_ => false,
+			});
+
+		if !self.is_finished() && owes_cleanup {
+			if let Some(abandoned) = self.abandoned.upgrade() {
+				abandoned.borrow_mut().push(Resumable {
+					scope: std::mem::replace(&mut self.scope, Scope::new()),
+					frames: std::mem::take(&mut self.frames),
+					result: Object::Null,
+					abandoned: self.abandoned.clone(),
+				});
+			}
+		}
+	}
+}
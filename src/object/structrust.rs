@@ -9,6 +9,13 @@ impl_downcast!(ObjectTrait);
 pub trait ObjectTrait: Downcast {
 	fn is_equals(&self, other: &Box<dyn ObjectTrait>) -> bool;
 	fn display(&self) -> String { format!("tst") }
+	fn contains(&self, _item: &Object) -> Option<bool> { None }
+	/// Called when the object is opened by a `with` block. The returned value
+	/// is bound to the block's name; `None` binds the object itself.
+	fn enter(&self) -> Option<GLResult> { None }
+	/// Called when a `with` block over the object is left, with the exception
+	/// that is leaving it, if any.
+	fn exit(&self, _exception: Option<&Exception>) -> Result<(), Exception> { Ok(()) }
 	fn clone(&self) -> Box<dyn ObjectTrait>;
 }
 
@@ -12,15 +12,21 @@ pub type GInt = BigInt;
 pub type GFloat = BigRational;
 pub type GBoolean = bool;
 pub type GString = String;
+pub type GChar = char;
+pub type GBytes = Vec<u8>;
 pub type GVec = Vec<Object>;
 pub type GTuple = Vec<Object>;
 pub type GHashMap = HashMap<Object, Object>;
+pub type GSet = HashSet<Object>;
 
 #[derive(PartialEq)]
 pub struct GFunction {
 	pub name: Option<String>,
-	pub params: Vec<String>,
+	pub params: Vec<Param>,
+	pub returns: Option<String>,
 	pub body: Block,
+	pub is_async: bool,
+	pub doc: Option<String>,
 }
 
 pub struct GFunctionNative {
@@ -33,6 +39,7 @@ pub struct GFunctionNative {
 pub struct GModule {
 	pub name: String,
 	pub path: String,
+	pub doc: Option<String>,
 	pub context: Scope,
 }
 
@@ -49,17 +56,42 @@ pub enum Object {
 	Float(GFloat),
 	Boolean(GBoolean),
 	String(GString),
+	Char(GChar),
+	Bytes(GBytes),
 	Vec(GVec),
 	Tuple(GTuple),
 	HashMap(GHashMap),
+	Set(GSet),
 	Fn(GFunction),
 	FnNative(GFunctionNative),
+	Generator(GGenerator),
+	Future(GFuture),
 	Module(GModule),
 	ModuleNative(GModuleNative),
 	ModuleDynLibrary(ModuleDynLibrary),
 	StructRust(StructRust),
 }
 
+/// Every name `Object::typer` gives, which type annotations may use.
+pub const TYPE_NAMES: [&str; 16] = [
+	"Null",
+	"Integer",
+	"Float",
+	"Boolean",
+	"String",
+	"Char",
+	"Bytes",
+	"Vec",
+	"Tuple",
+	"HashMap",
+	"Set",
+	"Fn",
+	"Generator",
+	"Future",
+	"Module",
+	"ObjectRust",
+];
+
 // Object
 
 impl Object {
@@ -70,17 +102,24 @@ impl Object {
 			Object::Float(_) => "Float",
 			Object::Boolean(_) => "Boolean",
 			Object::String(_) => "String",
+			Object::Char(_) => "Char",
+			Object::Bytes(_) => "Bytes",
 			Object::Vec(_) => "Vec",
 			Object::Tuple(_) => "Tuple",
 			Object::HashMap(_) => "HashMap",
+			Object::Set(_) => "Set",
 			Object::Fn(..) => "Fn",
 			Object::FnNative(..) => "Fn",
+			Object::Generator(_) => "Generator",
+			Object::Future(_) => "Future",
 			Object::Module(..) => "Module",
 			Object::ModuleNative(..) => "Module",
 			Object::ModuleDynLibrary(_) => "Module",
 			Object::StructRust(_) => "ObjectRust",
 		}
 	}
+
+	pub fn is_truthy(&self) -> bool { !matches!(self, Object::Null | Object::Boolean(false)) }
 }
 
 impl std::fmt::Display for Object {
@@ -91,6 +130,8 @@ impl std::fmt::Display for Object {
 			Object::Float(float) => write!(f, "{}", big_rational_to_string(float.clone())),
 			Object::Boolean(boolean) => write!(f, "{}", boolean),
 			Object::String(string) => write!(f, "{:?}", string),
+			Object::Char(c) => write!(f, "{:?}", c),
+			Object::Bytes(bytes) => write!(f, "{}", bytes_to_string(bytes)),
 			Object::Vec(vector) => {
 				let mut fmt_string: String = String::new();
 				for (i, object) in vector.iter().enumerate() {
@@ -121,8 +162,20 @@ impl std::fmt::Display for Object {
 				}
 				write!(f, "{{{}}}", fmt_string)
 			},
+			Object::Set(set) => {
+				let mut fmt_string: String = String::new();
+				for (i, object) in set.iter().enumerate() {
+					fmt_string.push_str(&format!("{}", object));
+					if i < set.len() - 1 {
+						fmt_string.push_str(", ");
+					}
+				}
+				write!(f, "#{{{}}}", fmt_string)
+			},
 			Object::Fn(gfn) => write!(f, "{}", gfn),
 			Object::FnNative(gfn) => write!(f, "{}", gfn),
+			Object::Generator(generator) => write!(f, "{}", generator),
+			Object::Future(future) => write!(f, "{}", future),
 			Object::Module(gm) => write!(f, "{}", gm),
 			Object::ModuleNative(gm) => write!(f, "{}", gm),
 			Object::ModuleDynLibrary(module) => {
@@ -144,6 +197,8 @@ impl Hash for Object {
 			Object::Float(ref f) => f.hash(state),
 			Object::Boolean(ref b) => b.hash(state),
 			Object::String(ref s) => s.hash(state),
+			Object::Char(ref c) => c.hash(state),
+			Object::Bytes(ref b) => b.hash(state),
 			Object::Vec(ref v) => v.hash(state),
 			Object::Tuple(ref t) => t.hash(state),
 			Object::ModuleDynLibrary(ref m) => m.get_path().hash(state),
@@ -160,11 +215,16 @@ impl Clone for Object {
 			Object::Float(f) => Object::from(f.clone()),
 			Object::Boolean(b) => Object::from(b.clone()),
 			Object::String(s) => Object::from(s.clone()),
+			Object::Char(c) => Object::from(*c),
+			Object::Bytes(b) => Object::Bytes(b.clone()),
 			Object::Vec(v) => Object::from(v.clone()),
-			Object::Tuple(t) => Object::from(t.clone()),
+			Object::Tuple(t) => Object::Tuple(t.clone()),
 			Object::HashMap(h) => Object::from(h.clone()),
+			Object::Set(s) => Object::from(s.clone()),
 			Object::Fn(gfn) => Object::from(gfn.clone()),
 			Object::FnNative(gfn) => Object::from(gfn.clone()),
+			Object::Generator(generator) => Object::Generator(generator.clone()),
+			Object::Future(future) => Object::Future(future.clone()),
 			Object::Module(gm) => Object::from(gm.clone()),
 			Object::ModuleNative(gm) => Object::from(gm.clone()),
 			Object::ModuleDynLibrary(m) => Object::ModuleDynLibrary(m.clone()),
@@ -189,6 +249,10 @@ impl From<GString> for Object {
 	fn from(data: GString) -> Self { Self::String(data) }
 }
 
+impl From<GChar> for Object {
+	fn from(data: GChar) -> Self { Self::Char(data) }
+}
+
 impl From<GVec> for Object {
 	fn from(data: GVec) -> Self { Self::Vec(data) }
 }
@@ -197,6 +261,10 @@ impl From<GHashMap> for Object {
 	fn from(data: GHashMap) -> Self { Self::HashMap(data) }
 }
 
+impl From<GSet> for Object {
+	fn from(data: GSet) -> Self { Self::Set(data) }
+}
+
 impl From<GFunction> for Object {
 	fn from(data: GFunction) -> Self { Self::Fn(data) }
 }
@@ -205,6 +273,14 @@ impl From<GFunctionNative> for Object {
 	fn from(data: GFunctionNative) -> Self { Self::FnNative(data) }
 }
 
+impl From<GGenerator> for Object {
+	fn from(data: GGenerator) -> Self { Self::Generator(data) }
+}
+
+impl From<GFuture> for Object {
+	fn from(data: GFuture) -> Self { Self::Future(data) }
+}
+
 impl From<GModule> for Object {
 	fn from(data: GModule) -> Self { Self::Module(data) }
 }
@@ -216,8 +292,11 @@ impl From<GModuleNative> for Object {
 // GFunction
 
 impl GFunction {
-	pub fn new(name: Option<String>, params: Vec<String>, body: Block) -> Self {
-		Self { name, params, body }
+	pub fn new(
+		name: Option<String>, params: Vec<Param>, returns: Option<String>, body: Block,
+		is_async: bool,
+	) -> Self {
+		Self { name, params, returns, body, is_async, doc: None }
 	}
 }
 
@@ -225,7 +304,7 @@ impl Display for GFunction {
 	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
 		let mut params_string: String = String::new();
 		for (i, param) in self.params.iter().enumerate() {
-			params_string.push_str(param);
+			params_string.push_str(&param.to_string());
 			if i < self.params.len() - 1 {
 				params_string.push_str(", ");
 			}
@@ -233,7 +312,8 @@ impl Display for GFunction {
 
 		write!(
 			f,
-			"<function {} ({})>",
+			"<{}function {} ({})>",
+			if self.is_async { "async " } else { "" },
 			match &self.name {
 				Some(name_fn) => name_fn.clone(),
 				None => format!("<anonymous>"),
@@ -244,7 +324,17 @@ impl Display for GFunction {
 }
 
 impl Clone for GFunction {
-	fn clone(&self) -> Self { Self::new(self.name.clone(), self.params.clone(), self.body.clone()) }
+	fn clone(&self) -> Self {
+		let mut function: Self = Self::new(
+			self.name.clone(),
+			self.params.clone(),
+			self.returns.clone(),
+			self.body.clone(),
+			self.is_async,
+		);
+		function.doc = self.doc.clone();
+		function
+	}
 }
 
 // GFunctionNative
@@ -277,7 +367,9 @@ impl PartialEq for GFunctionNative {
 // GModule
 
 impl GModule {
-	pub fn new(name: String, path: String, context: Scope) -> Self { Self { name, path, context } }
+	pub fn new(name: String, path: String, context: Scope) -> Self {
+		Self { name, path, doc: context.doc.clone(), context }
+	}
 
 	pub fn get(&self, name: String) -> Option<Object> {
 		match self.context.get(&name) {
@@ -285,6 +377,21 @@ impl GModule {
 			None => None,
 		}
 	}
+
+	/// Names starting with `_` are private. Once a module exports anything
+	/// with `export`, only its exported names are public.
+	pub fn is_public(&self, name: &str) -> bool {
+		!name.starts_with('_')
+			&& (self.context.exports.is_empty() || self.context.exports.contains(name))
+	}
+
+	/// The public names of the module, sorted.
+	pub fn public_names(&self) -> Vec<String> {
+		let mut names: Vec<String> =
+			self.context.store.keys().filter(|name| self.is_public(name)).cloned().collect();
+		names.sort();
+		names
+	}
 }
 
 impl Display for GModule {
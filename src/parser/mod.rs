@@ -2,10 +2,17 @@
 
 use crate::preludes::*;
 
+mod passert;
+mod pasync;
 mod patom;
 mod pblock;
 mod pcall;
+mod pcomprehension;
+mod pdefer;
+mod pdoc;
+mod pexport;
 mod pexpression;
+mod pfor;
 mod pfunction;
 mod phashmap;
 mod pimport;
@@ -15,16 +22,23 @@ mod plet;
 mod pnumber;
 mod pprefix;
 mod pproperty;
+mod pset;
 mod pstatement;
 mod ptuple;
+mod ptype;
 mod pvec;
+mod pwith;
+mod pyield;
 
 pub struct Parser {
 	ctoken: Token,
 	ntoken: Token,
+	previous_end: Position,
 	tokens_cache: Vec<Token>,
 	lexer: Lexer,
 	module: String,
+	loop_labels: Vec<Option<String>>,
+	async_body: bool,
 }
 
 impl Parser {
@@ -33,9 +47,12 @@ impl Parser {
 		let mut parser: Self = Self {
 			ctoken: Token::default(),
 			ntoken: Token::default(),
+			previous_end: Position::default(),
 			tokens_cache: Vec::new(),
 			lexer,
 			module,
+			loop_labels: Vec::new(),
+			async_body: false,
 		};
 
 		let _ = parser.next_token(false)?; // EOF
@@ -45,6 +62,10 @@ impl Parser {
 	}
 
 	fn next_token(&mut self, while_newline: bool) -> Result<(), Exception> {
+		if !self.ctoken.typer.is(TokenType::NEWLINE) {
+			self.previous_end = self.ctoken.position.end.copy();
+		}
+
 		self.ctoken = self.ntoken.copy();
 
 		if self.tokens_cache.len() > 0 {
@@ -62,12 +83,21 @@ impl Parser {
 
 	fn next_token_from_lexer(&mut self) -> Result<Token, Exception> { self.lexer.next() }
 
-	fn next_newline(&mut self) -> Result<(), Exception> {
-		while !self.ctoken.typer.is(TokenType::NEWLINE) && !self.ctoken.typer.is(TokenType::EOF) {
-			self.next_token(false)?; // all except NEWLINE and EOF
-		}
+	fn peek_token_after_ntoken(&mut self) -> Result<Token, Exception> {
+		let mut index: usize = 0;
 
-		Ok(())
+		loop {
+			if index == self.tokens_cache.len() {
+				let token: Token = self.next_token_from_lexer()?;
+				self.tokens_cache.push(token);
+			}
+
+			if !self.tokens_cache[index].typer.is(TokenType::NEWLINE) {
+				return Ok(self.tokens_cache[index].copy());
+			}
+
+			index += 1;
+		}
 	}
 
 	fn next_while_newline(&mut self) -> Result<(), Exception> {
@@ -85,17 +115,22 @@ impl Parser {
 			return Ok(None);
 		}
 
+		self.lexer.forget_lines_before(self.ctoken.position.start.line);
 		Ok(Some(self.parse_statement(true)?))
 	}
 
+	/// Like `next`, also returning where the statement starts.
+	pub fn next_positioned(&mut self) -> Result<Option<(Position, Statement)>, Exception> {
+		self.next_while_newline()?;
+		let position: Position = self.ctoken.position.start.copy();
+		Ok(self.next()?.map(|statement| (position, statement)))
+	}
+
 	pub fn run(&mut self) -> Result<AbstractSyntaxTree, Exception> {
 		let mut ast: AbstractSyntaxTree = AbstractSyntaxTree::new();
 
-		loop {
-			ast.push(match self.next()? {
-				Some(statement) => statement,
-				None => break,
-			});
+		while let Some((position, statement)) = self.next_positioned()? {
+			ast.push_positioned(position, statement);
 		}
 
 		Ok(ast)
@@ -0,0 +1,23 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_assert(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // ASSERT
+
+		let start: Position = self.ctoken.position.start.copy();
+		let condition: Expression = self.parse_expression(Precedence::Comma)?;
+		let source: String = self.lexer.source_text(&start, &self.previous_end);
+
+		if self.ctoken.typer.is(TokenType::COMMA) {
+			self.next_token(true)?; // COMMA
+			let position: Position = self.ctoken.position.start.copy();
+			let message: Expression = self.parse_expression(Precedence::Comma)?;
+			self.reject_await(message.contains_await(), "an assert message", position)?;
+			return Ok(Statement::Assert(condition, Some(message), source));
+		}
+
+		Ok(Statement::Assert(condition, None, source))
+	}
+}
@@ -0,0 +1,53 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn is_async_fn_statement(&mut self) -> Result<bool, Exception> {
+		if !self.ntoken.typer.is(TokenType::FN) {
+			return Ok(false);
+		}
+
+		Ok(matches!(self.peek_token_after_ntoken()?.typer, TokenType::IDENTIFIER(_)))
+	}
+
+	pub fn parse_async_function(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // ASYNC
+		self.parse_function(true)
+	}
+
+	pub fn parse_async_function_anonymous(&mut self) -> Result<Expression, Exception> {
+		self.next_token(true)?; // ASYNC
+
+		if !self.ctoken.typer.is(TokenType::FN) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected 'fn'"));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.parse_function_anonymous(true)
+	}
+
+	pub fn parse_await(&mut self) -> Result<Expression, Exception> {
+		self.next_token(true)?; // AWAIT
+		Ok(Expression::Await(Box::new(self.parse_expression(Precedence::Multiplicative)?)))
+	}
+
+	/// Fails on an `await` where an async function can't pause: in a
+	/// comprehension, a `defer` or the message of an `assert`.
+	pub fn reject_await(
+		&self, awaits: bool, place: &str, position: Position,
+	) -> Result<(), Exception> {
+		if self.async_body && awaits {
+			let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(format!(
+				"'await' can't be used in {} inside an async function",
+				place
+			)));
+			exception.push(ExceptionPoint::new(&self.module, position));
+			return Err(exception);
+		}
+
+		Ok(())
+	}
+}
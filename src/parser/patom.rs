@@ -22,12 +22,14 @@ impl Parser {
 				Expression::Literal(Literal::Null)
 			},
 			TokenType::INTEGER(integer_literal) => {
+				let literal: Literal = self.parse_integer(integer_literal)?;
 				self.next_token(false)?; // INTEGER
-				Expression::Literal(self.parse_integer(integer_literal)?)
+				Expression::Literal(literal)
 			},
 			TokenType::FLOAT(float_literal) => {
+				let literal: Literal = self.parse_float(float_literal)?;
 				self.next_token(false)?; // FLOAT
-				Expression::Literal(self.parse_float(float_literal)?)
+				Expression::Literal(literal)
 			},
 			TokenType::BOOLEAN(boolean_literal) => {
 				self.next_token(false)?; // BOOLEAN
@@ -37,11 +39,22 @@ impl Parser {
 				self.next_token(false)?; // STRING
 				Expression::Literal(Literal::String(string_literal))
 			},
+			TokenType::BYTES(bytes_literal) => {
+				self.next_token(false)?; // BYTES
+				Expression::Literal(Literal::Bytes(bytes_literal))
+			},
+			TokenType::CHAR(char_literal) => {
+				self.next_token(false)?; // CHAR
+				Expression::Literal(Literal::Char(char_literal))
+			},
 			TokenType::BANG | TokenType::PLUS | TokenType::MINUS => self.parse_prefix()?,
-			TokenType::FN => self.parse_function_anonymous()?,
+			TokenType::FN => self.parse_function_anonymous(false)?,
+			TokenType::ASYNC => self.parse_async_function_anonymous()?,
+			TokenType::AWAIT => self.parse_await()?,
 			TokenType::LeftParen => self.parse_tuple(None)?,
 			TokenType::LeftBracket => Expression::Literal(self.parse_vec()?),
 			TokenType::LeftBrace => Expression::Literal(self.parse_hashmap()?),
+			TokenType::HashBrace => Expression::Literal(self.parse_set()?),
 			_ => {
 				let mut exception: Exception =
 					Exception::not_runtime(Except::invalid_syntax("invalid syntax"));
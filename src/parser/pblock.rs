@@ -10,6 +10,16 @@ impl Parser {
 			self.next_token(true)?; // LeftBrace
 
 			while !self.ctoken.typer.is(TokenType::RightBrace) {
+				if let TokenType::ModuleDocComment(_) = self.ctoken.typer {
+					let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+						"'//!' doc comments are only allowed at module level",
+					));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+					return Err(exception);
+				}
+
+				let position: Position = self.ctoken.position.start.copy();
 				let mut statement: Statement = self.parse_statement(false)?;
 
 				if self.ctoken.typer.is(TokenType::RightBrace) {
@@ -20,11 +30,11 @@ impl Parser {
 					statement = self.parse_statement_final(statement)?;
 				}
 
-				block.push(statement)
+				block.push_positioned(position, statement)
 			}
 
 			self.next_token(false)?; // RightBrace
-			return Ok(Block(block.statements));
+			return Ok(Block(block.statements, block.positions));
 		}
 
 		let mut exception: Exception =
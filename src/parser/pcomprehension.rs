@@ -0,0 +1,127 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_comprehension(&mut self) -> Result<Vec<ComprehensionFor>, Exception> {
+		let mut clauses: Vec<ComprehensionFor> = Vec::new();
+
+		while self.ctoken.typer.is(TokenType::FOR) {
+			self.next_token(true)?; // FOR
+			let targets: Vec<String> = self.parse_comprehension_targets()?;
+
+			if !self.ctoken.typer.is(TokenType::IN) {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::invalid_syntax("expected 'in'"));
+				exception
+					.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+				return Err(exception);
+			}
+
+			self.next_token(true)?; // IN
+			let iter: Expression = self.parse_expression(Precedence::Comma)?;
+			self.next_while_newline()?;
+			let mut conditions: Vec<Expression> = Vec::new();
+
+			while self.ctoken.typer.is(TokenType::IF) {
+				self.next_token(true)?; // IF
+				conditions.push(self.parse_expression(Precedence::Comma)?);
+				self.next_while_newline()?;
+			}
+
+			clauses.push(ComprehensionFor { targets, iter, conditions });
+		}
+
+		Ok(clauses)
+	}
+
+	pub fn parse_comprehension_targets(&mut self) -> Result<Vec<String>, Exception> {
+		let parenthesized: bool = self.ctoken.typer.is(TokenType::LeftParen);
+		if parenthesized {
+			self.next_token(true)?; // LeftParen
+		}
+
+		let mut targets: Vec<String> = Vec::new();
+
+		loop {
+			match self.ctoken.typer.clone() {
+				TokenType::IDENTIFIER(target) => targets.push(target),
+				_ => {
+					let mut exception: Exception =
+						Exception::not_runtime(Except::invalid_syntax("expected identifier"));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+					return Err(exception);
+				},
+			}
+
+			self.next_token(true)?; // IDENTIFIER
+
+			if self.ctoken.typer.is(TokenType::COMMA) {
+				self.next_token(true)?; // COMMA
+			} else {
+				break;
+			}
+		}
+
+		if parenthesized {
+			if !self.ctoken.typer.is(TokenType::RightParen) {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::invalid_syntax("expected ',' or ')'"));
+				exception
+					.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+				return Err(exception);
+			}
+
+			self.next_token(true)?; // RightParen
+		}
+
+		Ok(targets)
+	}
+
+	fn expect_comprehension_end(&mut self, end: TokenType, message: &str) -> Result<(), Exception> {
+		if !self.ctoken.typer.is(end) {
+			let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(message));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(false) // RightBracket or RightBrace
+	}
+
+	/// A coroutine can't pause halfway through a comprehension, so one in an
+	/// async function must not `await`.
+	fn reject_await_in_comprehension(
+		&self, elements: &[&Expression], clauses: &[ComprehensionFor], position: Position,
+	) -> Result<(), Exception> {
+		let awaits: bool = elements.iter().any(|element| element.contains_await())
+			|| clauses.iter().any(ComprehensionFor::contains_await);
+		self.reject_await(awaits, "a comprehension", position)
+	}
+
+	pub fn parse_vec_comprehension(&mut self, element: Expression) -> Result<Literal, Exception> {
+		let position: Position = self.ctoken.position.start.copy();
+		let clauses: Vec<ComprehensionFor> = self.parse_comprehension()?;
+		self.expect_comprehension_end(TokenType::RightBracket, "expected ']'")?;
+		self.reject_await_in_comprehension(&[&element], &clauses, position)?;
+		Ok(Literal::VecComprehension(Box::new(element), clauses))
+	}
+
+	pub fn parse_set_comprehension(&mut self, element: Expression) -> Result<Literal, Exception> {
+		let position: Position = self.ctoken.position.start.copy();
+		let clauses: Vec<ComprehensionFor> = self.parse_comprehension()?;
+		self.expect_comprehension_end(TokenType::RightBrace, "expected '}'")?;
+		self.reject_await_in_comprehension(&[&element], &clauses, position)?;
+		Ok(Literal::SetComprehension(Box::new(element), clauses))
+	}
+
+	pub fn parse_hashmap_comprehension(
+		&mut self, key: Expression, value: Expression,
+	) -> Result<Literal, Exception> {
+		let position: Position = self.ctoken.position.start.copy();
+		let clauses: Vec<ComprehensionFor> = self.parse_comprehension()?;
+		self.expect_comprehension_end(TokenType::RightBrace, "expected '}'")?;
+		self.reject_await_in_comprehension(&[&key, &value], &clauses, position)?;
+		Ok(Literal::HashMapComprehension(Box::new((key, value)), clauses))
+	}
+}
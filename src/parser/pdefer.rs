@@ -0,0 +1,15 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_defer(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // DEFER
+
+		let position: Position = self.ctoken.position.start.copy();
+		let expression: Expression = self.parse_expression(Precedence::Lowest)?;
+		self.reject_await(expression.contains_await(), "'defer'", position)?;
+
+		Ok(Statement::Defer(expression))
+	}
+}
@@ -0,0 +1,38 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_doc(&mut self) -> Result<Statement, Exception> {
+		let mut lines: Vec<String> = Vec::new();
+
+		while let TokenType::DocComment(line) = self.ctoken.typer.clone() {
+			lines.push(line);
+			self.next_token(true)?; // DocComment
+		}
+
+		match self.ctoken.typer {
+			TokenType::LET | TokenType::FN | TokenType::ASYNC | TokenType::EXPORT =>
+				Ok(Statement::Doc(lines.join("\n"), Box::new(self.parse_statement(false)?))),
+			_ => {
+				let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+					"expected 'fn', 'let' or 'export' after doc comment",
+				));
+				exception
+					.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+				Err(exception)
+			},
+		}
+	}
+
+	pub fn parse_module_doc(&mut self) -> Result<Statement, Exception> {
+		let mut lines: Vec<String> = Vec::new();
+
+		while let TokenType::ModuleDocComment(line) = self.ctoken.typer.clone() {
+			lines.push(line);
+			self.next_token(true)?; // ModuleDocComment
+		}
+
+		Ok(Statement::ModuleDoc(lines.join("\n")))
+	}
+}
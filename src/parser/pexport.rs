@@ -0,0 +1,22 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_export(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // EXPORT
+
+		match self.ctoken.typer {
+			TokenType::LET | TokenType::FN | TokenType::ASYNC =>
+				Ok(Statement::Export(Box::new(self.parse_statement(false)?))),
+			_ => {
+				let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+					"expected 'let' or 'fn' after 'export'",
+				));
+				exception
+					.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+				Err(exception)
+			},
+		}
+	}
+}
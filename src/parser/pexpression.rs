@@ -15,12 +15,17 @@ impl Parser {
 				| TokenType::MINUS
 				| TokenType::ASTERISK
 				| TokenType::SLASH
+				| TokenType::PIPE
+				| TokenType::AMPERSAND
+				| TokenType::CARET
 				| TokenType::EQUAL
 				| TokenType::NotEqual
 				| TokenType::LessThan
 				| TokenType::LessThanEqual
 				| TokenType::GreaterThan
-				| TokenType::GreaterThanEqual => self.parse_infix(left)?,
+				| TokenType::GreaterThanEqual
+				| TokenType::IN
+				| TokenType::NOT => self.parse_infix(left)?,
 				TokenType::LeftParen => self.parse_call(left)?,
 				TokenType::LeftBracket => self.parse_index(left)?,
 				TokenType::DCOLON => self.parse_property(left)?,
@@ -0,0 +1,86 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_for(&mut self) -> Result<Statement, Exception> {
+		let label: Option<String> = match self.ctoken.typer.clone() {
+			TokenType::LABEL(label) => {
+				self.next_token(true)?; // LABEL
+
+				if !self.ctoken.typer.is(TokenType::COLON) {
+					let mut exception: Exception =
+						Exception::not_runtime(Except::invalid_syntax("expected ':'"));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+					return Err(exception);
+				}
+
+				self.next_token(true)?; // COLON
+				Some(label)
+			},
+			_ => None,
+		};
+
+		if !self.ctoken.typer.is(TokenType::FOR) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected 'for' after label"));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(true)?; // FOR
+		let targets: Vec<String> = self.parse_comprehension_targets()?;
+
+		if !self.ctoken.typer.is(TokenType::IN) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected 'in'"));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(true)?; // IN
+		let iter: Expression = self.parse_expression(Precedence::Lowest)?;
+
+		self.loop_labels.push(label.clone());
+		let body: Result<Block, Exception> = self.parse_block();
+		self.loop_labels.pop();
+
+		Ok(Statement::For { label, targets, iter, body: body? })
+	}
+
+	pub fn parse_break_continue(&mut self) -> Result<Statement, Exception> {
+		let is_break: bool = self.ctoken.typer.is(TokenType::BREAK);
+		let keyword: &str = if is_break { "break" } else { "continue" };
+
+		if self.loop_labels.is_empty() {
+			let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(format!(
+				"'{}' outside loop",
+				keyword
+			)));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(false)?; // BREAK or CONTINUE
+
+		let label: Option<String> = match self.ctoken.typer.clone() {
+			TokenType::LABEL(label) => {
+				if !self.loop_labels.contains(&Some(label.clone())) {
+					let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(
+						format!("use of undeclared label '{}", label),
+					));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+					return Err(exception);
+				}
+
+				self.next_token(false)?; // LABEL
+				Some(label)
+			},
+			_ => None,
+		};
+
+		Ok(if is_break { Statement::Break(label) } else { Statement::Continue(label) })
+	}
+}
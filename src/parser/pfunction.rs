@@ -43,7 +43,7 @@ impl Parser {
 		Err(exception)
 	}
 
-	pub fn parse_function(&mut self) -> Result<Statement, Exception> {
+	pub fn parse_function(&mut self, is_async: bool) -> Result<Statement, Exception> {
 		self.next_token(true)?; // FN
 
 		let name: String = match self.ctoken.typer.clone() {
@@ -58,7 +58,7 @@ impl Parser {
 		};
 
 		self.next_token(true)?; // IDENTIFIER
-		let mut params: Vec<String> = Vec::new();
+		let mut params: Vec<Param> = Vec::new();
 
 		if !self.ctoken.typer.is(TokenType::LeftParen) {
 			let mut exception: Exception =
@@ -69,9 +69,9 @@ impl Parser {
 		self.next_token(true)?; // LeftParen
 
 		while !self.ctoken.typer.is(TokenType::RightParen) {
-			if let TokenType::IDENTIFIER(argument) = self.ctoken.typer.clone() {
-				params.push(argument);
+			if let TokenType::IDENTIFIER(name) = self.ctoken.typer.clone() {
 				self.next_token(true)?; // IDENTIFIER
+				params.push(Param { name, typer: self.parse_type_annotation()? });
 			}
 
 			match &self.ctoken.typer {
@@ -100,13 +100,14 @@ impl Parser {
 		}
 
 		self.next_token(true)?; // RightParen
-		let body: Block = self.parse_block()?;
-		Ok(Statement::Fn { name, params, body })
+		let returns: Option<String> = self.parse_return_annotation()?;
+		let body: Block = self.parse_function_body(is_async)?;
+		Ok(Statement::Fn { name, params, returns, body, is_async })
 	}
 
-	pub fn parse_function_anonymous(&mut self) -> Result<Expression, Exception> {
+	pub fn parse_function_anonymous(&mut self, is_async: bool) -> Result<Expression, Exception> {
 		self.next_token(true)?; // FN
-		let mut params: Vec<String> = Vec::new();
+		let mut params: Vec<Param> = Vec::new();
 
 		if !self.ctoken.typer.is(TokenType::LeftParen) {
 			let mut exception: Exception =
@@ -117,9 +118,9 @@ impl Parser {
 		self.next_token(true)?; // LeftParen
 
 		while !self.ctoken.typer.is(TokenType::RightParen) {
-			if let TokenType::IDENTIFIER(argument) = self.ctoken.typer.clone() {
-				params.push(argument);
+			if let TokenType::IDENTIFIER(name) = self.ctoken.typer.clone() {
 				self.next_token(true)?; // IDENTIFIER
+				params.push(Param { name, typer: self.parse_type_annotation()? });
 			}
 
 			match &self.ctoken.typer {
@@ -148,7 +149,19 @@ impl Parser {
 		}
 
 		self.next_token(true)?; // RightParen
-		let body: Block = self.parse_block()?;
-		Ok(Expression::Fn { params, body })
+		let returns: Option<String> = self.parse_return_annotation()?;
+		let body: Block = self.parse_function_body(is_async)?;
+		Ok(Expression::Fn { params, returns, body, is_async })
+	}
+
+	/// Parses a function body. Loop labels of the enclosing code are not
+	/// visible inside it.
+	fn parse_function_body(&mut self, is_async: bool) -> Result<Block, Exception> {
+		let loop_labels: Vec<Option<String>> = std::mem::take(&mut self.loop_labels);
+		let async_body: bool = std::mem::replace(&mut self.async_body, is_async);
+		let body: Result<Block, Exception> = self.parse_block();
+		self.loop_labels = loop_labels;
+		self.async_body = async_body;
+		body
 	}
 }
@@ -20,9 +20,14 @@ impl Parser {
 
 			self.next_token(true)?; // COLON
 			let value: Expression = self.parse_expression(Precedence::Comma)?;
-			values.push((key, value));
 			self.next_while_newline()?;
 
+			if values.is_empty() && self.ctoken.typer.is(TokenType::FOR) {
+				return self.parse_hashmap_comprehension(key, value);
+			}
+
+			values.push((key, value));
+
 			match &self.ctoken.typer {
 				TokenType::COMMA => self.next_token(true)?,
 				TokenType::RightBrace => {},
@@ -6,14 +6,75 @@ impl Parser {
 	pub fn parse_import(&mut self) -> Result<Statement, Exception> {
 		self.next_token(true)?; // IMPORT
 
-		if let TokenType::STRING(name) = self.ctoken.typer.clone() {
+		let path: String = self.parse_import_path()?;
+		let alias: Option<String> = self.parse_import_alias()?;
+
+		Ok(Statement::Import(path, alias))
+	}
+
+	pub fn parse_import_from(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // FROM
+
+		if let TokenType::STRING(path) = self.ctoken.typer.clone() {
 			self.next_token(true)?; // STRING
-			return Ok(Statement::Import(name));
+
+			if !self.ctoken.typer.is(TokenType::IMPORT) {
+				return Err(self.import_syntax_err("expected 'import'"));
+			}
+
+			self.next_token(true)?; // IMPORT
+
+			let mut names: Vec<(String, Option<String>)> = Vec::new();
+
+			loop {
+				let name: String = self.parse_import_name()?;
+				let alias: Option<String> = self.parse_import_alias()?;
+				names.push((name, alias));
+
+				if !self.ctoken.typer.is(TokenType::COMMA) {
+					break;
+				}
+
+				self.next_token(true)?; // COMMA
+			}
+
+			return Ok(Statement::ImportFrom(path, names));
+		}
+
+		Err(self.import_syntax_err("expected string"))
+	}
+
+	fn parse_import_path(&mut self) -> Result<String, Exception> {
+		if let TokenType::STRING(path) = self.ctoken.typer.clone() {
+			self.next_token(false)?; // STRING
+			return Ok(path);
 		}
 
-		let mut exception: Exception =
-			Exception::not_runtime(Except::invalid_syntax("expected string"));
+		Err(self.import_syntax_err("expected string"))
+	}
+
+	fn parse_import_name(&mut self) -> Result<String, Exception> {
+		if let TokenType::IDENTIFIER(name) = self.ctoken.typer.clone() {
+			self.next_token(false)?; // IDENTIFIER
+			return Ok(name);
+		}
+
+		Err(self.import_syntax_err("expected identifier"))
+	}
+
+	fn parse_import_alias(&mut self) -> Result<Option<String>, Exception> {
+		if !self.ctoken.typer.is(TokenType::AS) {
+			return Ok(None);
+		}
+
+		self.next_token(true)?; // AS
+
+		Ok(Some(self.parse_import_name()?))
+	}
+
+	fn import_syntax_err(&self, message: &str) -> Exception {
+		let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(message));
 		exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
-		Err(exception)
+		exception
 	}
 }
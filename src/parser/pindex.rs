@@ -5,7 +5,40 @@ use crate::preludes::*;
 impl Parser {
 	pub fn parse_index(&mut self, left: Expression) -> Result<Expression, Exception> {
 		self.next_token(true)?; // LeftBracket
-		let index: Expression = self.parse_expression(Precedence::Lowest)?;
+
+		let index: Option<Expression> = match self.ctoken.typer {
+			TokenType::COLON => None,
+			_ => Some(self.parse_expression(Precedence::Lowest)?),
+		};
+		self.next_while_newline()?;
+
+		if self.ctoken.typer.is(TokenType::COLON) {
+			return self.parse_slice(left, index);
+		}
+
+		if !self.ctoken.typer.is(TokenType::RightBracket) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected ']'"));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(false)?; // RightBracket
+		match index {
+			Some(index) => Ok(Expression::Index(Box::new(left), Box::new(index))),
+			None => unreachable!(),
+		}
+	}
+
+	fn parse_slice(
+		&mut self, left: Expression, start: Option<Expression>,
+	) -> Result<Expression, Exception> {
+		self.next_token(true)?; // COLON
+
+		let end: Option<Expression> = match self.ctoken.typer {
+			TokenType::RightBracket => None,
+			_ => Some(self.parse_expression(Precedence::Lowest)?),
+		};
 		self.next_while_newline()?;
 
 		if !self.ctoken.typer.is(TokenType::RightBracket) {
@@ -16,6 +49,6 @@ impl Parser {
 		}
 
 		self.next_token(false)?; // RightBracket
-		Ok(Expression::Index(Box::new(left), Box::new(index)))
+		Ok(Expression::Slice(Box::new(left), start.map(Box::new), end.map(Box::new)))
 	}
 }
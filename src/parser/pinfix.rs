@@ -11,12 +11,28 @@ impl Parser {
 			TokenType::MINUS => infix = Infix::Minus,
 			TokenType::ASTERISK => infix = Infix::Multiply,
 			TokenType::SLASH => infix = Infix::Divide,
+			TokenType::PIPE => infix = Infix::BitOr,
+			TokenType::AMPERSAND => infix = Infix::BitAnd,
+			TokenType::CARET => infix = Infix::BitXor,
 			TokenType::EQUAL => infix = Infix::Equal,
 			TokenType::NotEqual => infix = Infix::NotEqual,
 			TokenType::LessThan => infix = Infix::LessThan,
 			TokenType::LessThanEqual => infix = Infix::LessThanEqual,
 			TokenType::GreaterThan => infix = Infix::GreaterThan,
 			TokenType::GreaterThanEqual => infix = Infix::GreaterThanEqual,
+			TokenType::IN => infix = Infix::In,
+			TokenType::NOT => {
+				if !self.ntoken.typer.is(TokenType::IN) {
+					let mut exception: Exception =
+						Exception::not_runtime(Except::invalid_syntax("expected 'in'"));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ntoken.position.start.copy()));
+					return Err(exception);
+				}
+
+				self.next_token(true)?; // NOT
+				infix = Infix::NotIn;
+			},
 			_ => {},
 		};
 
@@ -8,12 +8,13 @@ impl Parser {
 
 		if let TokenType::IDENTIFIER(name) = self.ctoken.typer.clone() {
 			self.next_token(true)?; // IDENTIFIER
+			let typer: Option<String> = self.parse_type_annotation()?;
 
 			if self.ctoken.typer.is(TokenType::ASSIGN) {
 				self.next_token(true)?; // ASSIGN
 
 				let value: Expression = self.parse_expression(Precedence::Lowest)?;
-				return Ok(Statement::Let(name, value));
+				return Ok(Statement::Let(name, typer, value));
 			}
 
 			let mut exception: Exception =
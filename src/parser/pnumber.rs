@@ -4,10 +4,40 @@ use crate::preludes::*;
 
 impl Parser {
 	pub fn parse_integer(&mut self, integer_literal: String) -> Result<Literal, Exception> {
-		Ok(Literal::Integer(BigInt::parse_bytes(integer_literal.as_bytes(), 10).unwrap()))
+		let (digits, radix): (&str, u32) = match integer_literal.get(..2) {
+			Some("0x") => (&integer_literal[2..], 16),
+			Some("0b") => (&integer_literal[2..], 2),
+			Some("0o") => (&integer_literal[2..], 8),
+			_ => (&integer_literal[..], 10),
+		};
+
+		match BigInt::parse_bytes(digits.as_bytes(), radix) {
+			Some(integer) => Ok(Literal::Integer(integer)),
+			None => self.invalid_number(integer_literal),
+		}
 	}
 
+	/// Parses `1.5`, `0.(3)` or `6.02e23` into an exact rational.
 	pub fn parse_float(&mut self, float_literal: String) -> Result<Literal, Exception> {
-		Ok(Literal::Float(str_to_big_rational(&float_literal).unwrap()))
+		let (mantissa, exponent): (&str, &str) =
+			float_literal.split_once('e').unwrap_or((&float_literal[..], "0"));
+
+		let (mantissa, exponent): (BigRational, i32) =
+			match (str_to_big_rational(mantissa), exponent.parse::<i32>()) {
+				(Ok(mantissa), Ok(exponent)) => (mantissa, exponent),
+				_ => return self.invalid_number(float_literal),
+			};
+		let scale: BigRational = BigRational::from_integer(10.to_bigint().unwrap()).pow(exponent);
+
+		Ok(Literal::Float(mantissa * scale))
+	}
+
+	fn invalid_number(&self, literal: String) -> Result<Literal, Exception> {
+		let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(format!(
+			"invalid number literal '{}'",
+			literal
+		)));
+		exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+		Err(exception)
 	}
 }
@@ -0,0 +1,36 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_set(&mut self) -> Result<Literal, Exception> {
+		self.next_token(true)?; // HashBrace
+		let mut values: Vec<Expression> = Vec::new();
+
+		while !self.ctoken.typer.is(TokenType::RightBrace) {
+			let value: Expression = self.parse_expression(Precedence::Comma)?;
+			self.next_while_newline()?;
+
+			if values.is_empty() && self.ctoken.typer.is(TokenType::FOR) {
+				return self.parse_set_comprehension(value);
+			}
+
+			values.push(value);
+
+			match &self.ctoken.typer {
+				TokenType::COMMA => self.next_token(true)?, // COMMA
+				TokenType::RightBrace => {},
+				_ => {
+					let mut exception: Exception =
+						Exception::not_runtime(Except::invalid_syntax("expected ',' or '}'"));
+					exception
+						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+					return Err(exception);
+				},
+			}
+		}
+
+		self.next_token(false)?; // RightBrace
+		Ok(Literal::Set(values))
+	}
+}
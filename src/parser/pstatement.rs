@@ -13,32 +13,21 @@ impl Parser {
 				let value: Expression = self.parse_expression(Precedence::Lowest)?;
 				Statement::LetAlter(identifier, value)
 			},
-			TokenType::CommentLine => {
-				self.next_newline()?;
-				return Ok(Statement::Expression(Expression::Literal(Literal::Null)));
-			},
-			TokenType::CommentBlockOpen => {
-				while !self.ctoken.typer.is(TokenType::CommentBlockClose)
-					&& !self.ctoken.typer.is(TokenType::EOF)
-				{
-					self.next_token(false)?;
-				}
-
-				if self.ctoken.typer.is(TokenType::EOF) {
-					let mut exception: Exception =
-						Exception::not_runtime(Except::invalid_syntax("unexpected EOF"));
-					exception
-						.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
-					return Err(exception);
-				}
-
-				self.next_token(true)?; // CommentBlockClose
-				return Ok(Statement::Expression(Expression::Literal(Literal::Null)));
-			},
+			TokenType::ModuleDocComment(_) => return self.parse_module_doc(),
+			TokenType::DocComment(_) => self.parse_doc()?,
 			TokenType::LET => self.parse_let()?,
 			TokenType::FN if self.is_fn_statement_anonymous()? == "statement" =>
-				self.parse_function()?,
+				self.parse_function(false)?,
+			TokenType::ASYNC if self.is_async_fn_statement()? => self.parse_async_function()?,
 			TokenType::IMPORT => self.parse_import()?,
+			TokenType::FROM => self.parse_import_from()?,
+			TokenType::EXPORT => self.parse_export()?,
+			TokenType::YIELD => self.parse_yield()?,
+			TokenType::ASSERT => self.parse_assert()?,
+			TokenType::DEFER => self.parse_defer()?,
+			TokenType::WITH => self.parse_with()?,
+			TokenType::LABEL(_) | TokenType::FOR => self.parse_for()?,
+			TokenType::BREAK | TokenType::CONTINUE => self.parse_break_continue()?,
 			_ => Statement::Expression(self.parse_expression(Precedence::Lowest)?),
 		};
 
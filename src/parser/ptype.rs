@@ -0,0 +1,40 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	/// Parses an optional `: Type` after a parameter or `let` name.
+	pub fn parse_type_annotation(&mut self) -> Result<Option<String>, Exception> {
+		if !self.ctoken.typer.is(TokenType::COLON) {
+			return Ok(None);
+		}
+
+		self.next_token(true)?; // COLON
+		self.parse_type_name().map(Some)
+	}
+
+	/// Parses an optional `-> Type` after a parameter list.
+	pub fn parse_return_annotation(&mut self) -> Result<Option<String>, Exception> {
+		if !self.ctoken.typer.is(TokenType::ARROW) {
+			return Ok(None);
+		}
+
+		self.next_token(true)?; // ARROW
+		self.parse_type_name().map(Some)
+	}
+
+	fn parse_type_name(&mut self) -> Result<String, Exception> {
+		let message: String = match self.ctoken.typer.clone() {
+			TokenType::IDENTIFIER(typer) if TYPE_NAMES.contains(&typer.as_str()) => {
+				self.next_token(true)?; // IDENTIFIER
+				return Ok(typer);
+			},
+			TokenType::IDENTIFIER(typer) => format!("unknown type '{}'", typer),
+			_ => String::from("expected type name"),
+		};
+
+		let mut exception: Exception = Exception::not_runtime(Except::invalid_syntax(message));
+		exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+		Err(exception)
+	}
+}
@@ -8,9 +8,15 @@ impl Parser {
 		let mut values: Vec<Expression> = Vec::new();
 
 		while !self.ctoken.typer.is(TokenType::RightBracket) {
-			values.push(self.parse_expression(Precedence::Comma)?);
+			let value: Expression = self.parse_expression(Precedence::Comma)?;
 			self.next_while_newline()?;
 
+			if values.is_empty() && self.ctoken.typer.is(TokenType::FOR) {
+				return self.parse_vec_comprehension(value);
+			}
+
+			values.push(value);
+
 			match &self.ctoken.typer {
 				TokenType::COMMA => self.next_token(true)?, // COMMA
 				TokenType::RightBracket => {},
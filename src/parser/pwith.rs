@@ -0,0 +1,35 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_with(&mut self) -> Result<Statement, Exception> {
+		self.next_token(true)?; // WITH
+
+		let value: Expression = self.parse_expression(Precedence::Lowest)?;
+
+		if !self.ctoken.typer.is(TokenType::AS) {
+			let mut exception: Exception =
+				Exception::not_runtime(Except::invalid_syntax("expected 'as'"));
+			exception.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+			return Err(exception);
+		}
+
+		self.next_token(true)?; // AS
+
+		let name: String = match self.ctoken.typer.clone() {
+			TokenType::IDENTIFIER(name) => name,
+			_ => {
+				let mut exception: Exception =
+					Exception::not_runtime(Except::invalid_syntax("expected identifier"));
+				exception
+					.push(ExceptionPoint::new(&self.module, self.ctoken.position.start.copy()));
+				return Err(exception);
+			},
+		};
+
+		self.next_token(true)?; // IDENTIFIER
+
+		Ok(Statement::With { value, name, body: self.parse_block()? })
+	}
+}
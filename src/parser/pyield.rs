@@ -0,0 +1,15 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+use crate::preludes::*;
+
+impl Parser {
+	pub fn parse_yield(&mut self) -> Result<Statement, Exception> {
+		self.next_token(false)?; // YIELD
+
+		match &self.ctoken.typer {
+			TokenType::NEWLINE | TokenType::SEMICOLON | TokenType::RightBrace | TokenType::EOF =>
+				Ok(Statement::Yield(Expression::Literal(Literal::Null))),
+			_ => Ok(Statement::Yield(self.parse_expression(Precedence::Lowest)?)),
+		}
+	}
+}
@@ -1,86 +1,133 @@
 // Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
 
 use std::fs::File;
-use std::io::{self, BufReader, Read};
+use std::io::{self, Cursor, Read};
 
-enum SourceType {
-	String(String),
-	File(BufReader<File>),
-}
+use crate::preludes::*;
+
+const BUFFER_SIZE: usize = 8192;
 
+/// The characters of a script, decoded from UTF-8 as they are read. The
+/// bytes may come from a string, a byte slice, a file or any other reader.
 pub struct Source {
-	chars_cache: Vec<char>,
-	data: SourceType,
+	reader: Box<dyn Read>,
+	buffer: Box<[u8]>,
+	start: usize,
+	end: usize,
 	eof: bool,
+	offset: usize,
+	position: Position,
 }
 
 impl Source {
 	pub fn from_string<T: Into<String>>(string: T) -> Self {
-		Self { chars_cache: Vec::new(), data: SourceType::String(string.into()), eof: false }
+		Self::from_reader(Cursor::new(string.into().into_bytes()))
 	}
 
+	pub fn from_bytes(bytes: &[u8]) -> Self { Self::from_reader(Cursor::new(bytes.to_vec())) }
+
 	pub fn from_filename<T: Into<String>>(filename: T) -> io::Result<Self> {
 		let file: File = File::open(filename.into())?;
+		Ok(Self::from_reader(file))
+	}
+
+	pub fn from_stdin() -> Self { Self::from_reader(io::stdin()) }
 
-		Ok(Self {
-			chars_cache: Vec::new(),
-			data: SourceType::File(BufReader::new(file)),
+	pub fn from_reader<R: Read + 'static>(reader: R) -> Self {
+		Self {
+			reader: Box::new(reader),
+			buffer: vec![0; BUFFER_SIZE].into_boxed_slice(),
+			start: 0,
+			end: 0,
 			eof: false,
-		})
+			offset: 0,
+			position: Position::default(),
+		}
 	}
 
-	pub fn next_char(&mut self) -> Option<char> {
-		if self.chars_cache.len() > 0 {
-			let chc: char = self.chars_cache.remove(0);
-			return Some(chc);
+	/// The byte offset of the next character.
+	pub fn offset(&self) -> usize { self.offset }
+
+	/// The line and column of the next character.
+	pub fn position(&self) -> Position { self.position.copy() }
+
+	/// Decodes the next character, or `None` at the end of the input. Bytes
+	/// that are not UTF-8 are an error; nothing is skipped.
+	pub fn next_char(&mut self) -> Result<Option<char>, Exception> {
+		self.fill()?;
+
+		let window: &[u8] = &self.buffer[self.start..self.end.min(self.start + 4)];
+
+		if window.is_empty() {
+			return Ok(None);
 		}
 
-		match &mut self.data {
-			SourceType::String(string) =>
-				if !self.eof && string.len() > 0 {
-					let mut limite_read_chars: usize = 32;
-
-					if string.len() < limite_read_chars {
-						limite_read_chars = string.len();
-					}
-
-					loop {
-						let string_buffer: String =
-							if let Some(fstr) = string.get(0..limite_read_chars) {
-								format!("{}", fstr)
-							} else {
-								limite_read_chars += 1;
-								continue;
-							};
-
-						{
-							let fstring: String =
-								format!("{}", string.get(limite_read_chars..).unwrap());
-							string.clear();
-							string.push_str(&fstring);
-						}
-
-						self.chars_cache.extend(string_buffer.chars());
-						break;
-					}
-				},
-			SourceType::File(file) =>
-				if !self.eof {
-					let mut buffer: [u8; 256] = [0; 256];
-					let n: usize = file.read(&mut buffer).unwrap();
-
-					if let Ok(string_buffer) = String::from_utf8(buffer[..n].to_vec()) {
-						for ch in string_buffer.chars() {
-							self.chars_cache.push(ch);
-						}
-					}
-				},
+		let valid: &str = match std::str::from_utf8(window) {
+			Ok(valid) => valid,
+			Err(err) if err.valid_up_to() > 0 =>
+				std::str::from_utf8(&window[..err.valid_up_to()]).unwrap(),
+			Err(err) => {
+				let message: String = match err.error_len() {
+					Some(_) => format!(
+						"invalid UTF-8 byte 0x{:02x} at byte offset {}",
+						window[0], self.offset
+					),
+					None => format!("incomplete UTF-8 character at byte offset {}", self.offset),
+				};
+				return Err(Exception::not_runtime(Except::invalid_syntax(message)));
+			},
+		};
+
+		let c: char = valid.chars().next().unwrap();
+
+		// A byte order mark takes no column of the first line.
+		if c == '\n' {
+			self.position.line += 1;
+			self.position.column = 0;
+		} else if !(c == '\u{feff}' && self.offset == 0) {
+			self.position.column += 1;
 		}
 
-		if self.chars_cache.len() > 0 {
-			return self.next_char();
-		} else {
-			None
+		self.start += c.len_utf8();
+		self.offset += c.len_utf8();
+
+		Ok(Some(c))
+	}
+
+	/// Reads until a whole character is buffered or the input ends, so a
+	/// character split between two reads is never lost.
+	fn fill(&mut self) -> Result<(), Exception> {
+		while !self.eof
+			&& (self.start == self.end
+				|| self.end - self.start < utf8_width(self.buffer[self.start]))
+		{
+			self.buffer.copy_within(self.start..self.end, 0);
+			self.end -= self.start;
+			self.start = 0;
+
+			match self.reader.read(&mut self.buffer[self.end..]) {
+				Ok(0) => self.eof = true,
+				Ok(n) => self.end += n,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => {
+					return Err(Exception::not_runtime(Except::error(format!(
+						"can't read source: {}",
+						err
+					))));
+				},
+			}
 		}
+
+		Ok(())
+	}
+}
+
+/// The length of the UTF-8 sequence a byte starts; invalid bytes count as one.
+fn utf8_width(byte: u8) -> usize {
+	match byte {
+		0xc0..=0xdf => 2,
+		0xe0..=0xef => 3,
+		0xf0..=0xf7 => 4,
+		_ => 1,
 	}
 }
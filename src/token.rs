@@ -6,6 +6,15 @@ use crate::position::Position;
 pub struct Token {
 	pub typer: TokenType,
 	pub position: TokenPosition,
+	/// Whitespace and comments before the token; only kept by a lossless
+	/// `Lexer`.
+	pub leading_trivia: String,
+	/// The token exactly as written in the source; only kept by a lossless
+	/// `Lexer`.
+	pub text: String,
+	/// Whitespace and comments after the token on its line; only kept by a
+	/// lossless `Lexer`.
+	pub trailing_trivia: String,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -16,11 +25,10 @@ pub struct TokenPosition {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TokenType {
-	EOF,               // \0
-	NEWLINE,           // \n
-	CommentLine,       // //
-	CommentBlockOpen,  // /*
-	CommentBlockClose, // */
+	EOF,                      // \0
+	NEWLINE,                  // \n
+	DocComment(String),       // ///
+	ModuleDocComment(String), // //!
 
 	// identifier and literals
 	IDENTIFIER(String),
@@ -29,17 +37,38 @@ pub enum TokenType {
 	FLOAT(String),
 	BOOLEAN(bool),
 	STRING(String),
+	CHAR(char),
+	BYTES(Vec<u8>),
+	LABEL(String),
 
 	// keywords
 	LET,
 	FN,
+	ASYNC,
+	AWAIT,
 	IMPORT,
+	FROM,
+	EXPORT,
+	ASSERT,
+	DEFER,
+	WITH,
+	AS,
+	YIELD,
+	FOR,
+	BREAK,
+	CONTINUE,
+	IF,
+	IN,
+	NOT,
 
 	// operators
 	PLUS,             // +
 	MINUS,            // -
 	ASTERISK,         // *
 	SLASH,            // /
+	PIPE,             // |
+	AMPERSAND,        // &
+	CARET,            // ^
 	ASSIGN,           // =
 	BANG,             // !
 	EQUAL,            // ==
@@ -48,6 +77,7 @@ pub enum TokenType {
 	LessThanEqual,    // <=
 	GreaterThan,      // >
 	GreaterThanEqual, // >=
+	ARROW,            // ->
 
 	// punctuations
 	DOT,          // .
@@ -61,14 +91,29 @@ pub enum TokenType {
 	RightBracket, // ]
 	LeftBrace,    // {
 	RightBrace,   // }
+	HashBrace,    // #{
 }
 
 impl Token {
-	pub fn new(typer: TokenType, position: TokenPosition) -> Self { Self { typer, position } }
+	pub fn new(typer: TokenType, position: TokenPosition) -> Self {
+		Self {
+			typer,
+			position,
+			leading_trivia: String::new(),
+			text: String::new(),
+			trailing_trivia: String::new(),
+		}
+	}
 
 	pub fn default() -> Self { Self::new(TokenType::EOF, TokenPosition::default()) }
 
-	pub fn copy(&self) -> Self { Self::new(self.typer.clone(), self.position.copy()) }
+	pub fn copy(&self) -> Self { self.clone() }
+
+	/// The source the token was lexed from, trivia included. Concatenating
+	/// every token of a lossless `Lexer` gives back the whole source.
+	pub fn to_source(&self) -> String {
+		format!("{}{}{}", self.leading_trivia, self.text, self.trailing_trivia)
+	}
 }
 
 impl TokenPosition {
@@ -41,6 +41,27 @@ pub fn big_rational_to_string(mut n: BigRational) -> String {
 	output
 }
 
+/// Formats bytes as a `b"..."` literal, escaping everything that is not
+/// printable ASCII.
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+	let mut string: String = String::from("b\"");
+
+	for byte in bytes {
+		match byte {
+			b'"' => string.push_str("\\\""),
+			b'\\' => string.push_str("\\\\"),
+			b'\n' => string.push_str("\\n"),
+			b'\r' => string.push_str("\\r"),
+			b'\t' => string.push_str("\\t"),
+			0x20..=0x7e => string.push(*byte as char),
+			byte => string.push_str(&format!("\\x{:02x}", byte)),
+		}
+	}
+
+	string.push('"');
+	string
+}
+
 pub fn str_to_big_rational(string: &str) -> Result<BigRational, ()> {
 	match REGEX.captures(string) {
 		Some(captures) => {
@@ -73,8 +94,11 @@ fn frac(neg: bool, integer: &str, fractional: &str) -> BigRational {
 	for _ in 0..fractional.len() {
 		a *= &*TEN;
 	}
-	let b: BigRational = BigRational::new(BigInt::from_str(fractional).unwrap(), a)
-		+ BigInt::from_str(integer).unwrap();
+	let fractional: BigInt = match BigInt::from_str(fractional) {
+		Ok(fractional) => fractional,
+		Err(_) => BigInt::zero(),
+	};
+	let b: BigRational = BigRational::new(fractional, a) + BigInt::from_str(integer).unwrap();
 	if neg {
 		-b
 	} else {
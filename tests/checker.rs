@@ -0,0 +1,108 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+extern crate gl_core;
+
+use gl_core::preludes::*;
+
+fn check(source: &str) -> Vec<Exception> {
+	Checker::new("tests/checker").check_source(Source::from_string(source)).unwrap()
+}
+
+#[test]
+fn dynamic_programs_pass() {
+	let source: &str = "
+		fn add(a, b) { a + b }
+		let x = 1
+		x = \"text\"
+		let y = x + \"!\"
+		add(1, 2)
+		add(\"a\", \"b\")
+		fn uses_caller() { value + 1 }
+		for item in [1, \"a\"] { let doubled = item * 2 }
+		let words = [word * 2 for word in [\"a\", \"b\"]]
+	";
+	assert_eq!(0, check(source).len());
+}
+
+#[test]
+fn reports_type_errors() {
+	let source: &str = "let n = 1 + \"a\"
+fn area(w: Integer, h: Integer) -> Integer { w * h }
+area(\"6\", 7)
+let s: String = area(1, 2)
+fn name() -> String { 42 }
+for c in 42 {}
+-\"x\"
+area(1)
+42()";
+	let messages: Vec<(usize, String)> = check(source)
+		.into_iter()
+		.map(|exception| (exception.exception_points[0].position.line, exception.except.message))
+		.collect();
+
+	assert_eq!(
+		vec![
+			(0, String::from("unsupported operand type(s) for +: 'Integer' and 'String'")),
+			(2, String::from("argument 'w' of area() must be Integer, not String")),
+			(3, String::from("variable 's' must be String, not Integer")),
+			(4, String::from("return value of name() must be String, not Integer")),
+			(5, String::from("'Integer' object is not iterable")),
+			(6, String::from("bad operand type for unary -: 'String'")),
+			(7, String::from("area() expected 2 argument, found 1")),
+			(8, String::from("'Integer' object is not callable")),
+		],
+		messages
+	);
+}
+
+#[test]
+fn infers_through_bindings() {
+	let source: &str = "
+		fn total(a: Integer, b: Float) { a + b }
+		let sum = total(1, 2.5)
+		let text: String = sum
+		let set = #{1} | #{2}
+		let bad = set + 1
+	";
+	let messages: Vec<String> =
+		check(source).into_iter().map(|exception| exception.except.message).collect();
+
+	assert_eq!(
+		vec![
+			String::from("variable 'text' must be String, not Float"),
+			String::from("unsupported operand type(s) for +: 'Set' and 'Integer'"),
+		],
+		messages
+	);
+}
+
+#[test]
+fn reports_nested_positions() {
+	let source: &str = "fn area(w, h) {
+	let unit = 1
+	unit + \"m\"
+}
+for x in [1] {
+	-\"x\"
+}
+fn name() -> String {
+	42
+}";
+	let positions: Vec<(usize, usize)> = check(source)
+		.into_iter()
+		.map(|exception| {
+			let position: &Position = &exception.exception_points[0].position;
+			(position.line, position.column)
+		})
+		.collect();
+	assert_eq!(vec![(2, 1), (5, 1), (7, 0)], positions);
+
+	let lexer: Lexer = Lexer::new(Source::from_string(source), "tests/checker");
+	let ast: AbstractSyntaxTree = Parser::new(lexer).unwrap().run().unwrap();
+	let lines: Vec<usize> = Checker::new("tests/checker")
+		.check(&ast)
+		.into_iter()
+		.map(|exception| exception.exception_points[0].position.line)
+		.collect();
+	assert_eq!(vec![2, 5, 7], lines);
+}
@@ -0,0 +1,881 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+extern crate gl_core;
+
+use gl_core::preludes::*;
+
+fn eval(source: &str) -> GLResult { Interpreter::new().eval(source) }
+
+#[test]
+fn generator() {
+	let source: &str = "
+		fn count(start) {
+			yield start
+			let start = start + 1
+			yield start
+		}
+		let counter = count(41)
+		[next(counter), next(counter), next(counter)]
+	";
+
+	let expected: Object = Object::Vec(vec![
+		Object::Integer(41.to_bigint().unwrap()),
+		Object::Integer(42.to_bigint().unwrap()),
+		Object::Null,
+	]);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn generator_in() {
+	let source: &str = "
+		fn values() { yield 1; yield 2; yield 3 }
+		let gen = values()
+		(2 in gen, next(gen), 2 not in gen)
+	";
+
+	let expected: Object = Object::Tuple(vec![
+		Object::Boolean(true),
+		Object::Integer(3.to_bigint().unwrap()),
+		Object::Boolean(true),
+	]);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn generator_nested_blocks() {
+	let source: &str = "
+		fn g() { for x in [1, 2, 3] { yield x } }
+		vec(g())
+	";
+	let expected: Object = Object::Vec(vec![
+		Object::Integer(1.to_bigint().unwrap()),
+		Object::Integer(2.to_bigint().unwrap()),
+		Object::Integer(3.to_bigint().unwrap()),
+	]);
+	assert!(expected == eval(source).unwrap());
+
+	let source: &str = "
+		fn pairs() {
+			'rows: for row in [[1, 2, 3], [4, 5], [6]] {
+				for cell in row {
+					yield cell
+					continue 'rows
+				}
+			}
+			yield 0
+		}
+		vec(pairs())
+	";
+	let expected: Object = Object::Vec(vec![
+		Object::Integer(1.to_bigint().unwrap()),
+		Object::Integer(4.to_bigint().unwrap()),
+		Object::Integer(6.to_bigint().unwrap()),
+		Object::Integer(0.to_bigint().unwrap()),
+	]);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn generator_in_with() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	interpreter.set_global("resource", Object::StructRust(StructRust::new(Box::new(Resource))));
+
+	let source: &str = "
+		fn lines() {
+			with resource as r {
+				defer log(\"defer\")
+				for x in [1, 2] { yield x }
+			}
+			log(\"done\")
+		}
+		let gen = lines()
+		log(next(gen))
+		log(next(gen))
+		next(gen)
+	";
+	assert!(Object::Null == interpreter.eval(source).unwrap());
+	assert_eq!(vec!["enter", "1", "2", "defer", "exit false", "done"], logged());
+}
+
+#[test]
+fn generator_dropped_runs_defers() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	interpreter.set_global("resource", Object::StructRust(StructRust::new(Box::new(Resource))));
+
+	let source: &str = "
+		fn numbers() {
+			defer log(\"closed\")
+			with resource as r {
+				for x in [1, 2, 3] { yield x }
+			}
+		}
+		for x in numbers() {
+			log(x)
+			break
+		}
+		log(\"after\")
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["enter", "1", "exit false", "closed", "after"], logged());
+
+	let source: &str = "
+		fn first() { defer log(\"closed\"); yield 1; yield 2 }
+		fn take() { let gen = first(); next(gen) }
+		log(take())
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["closed", "1"], logged());
+
+	let source: &str = "
+		fn unstarted() { defer log(\"closed\"); yield 1 }
+		unstarted()
+	";
+	interpreter.eval(source).unwrap();
+	assert!(logged().is_empty());
+}
+
+#[test]
+fn yield_outside_function() {
+	assert!(eval("yield 42").is_err());
+}
+
+#[test]
+fn async_await() {
+	let source: &str = "
+		async fn double(x) {
+			await sleep(1)
+			x * 2
+		}
+		async fn main() {
+			let a = await double(20)
+			a + 2
+		}
+		await main()
+	";
+
+	assert!(Object::Integer(42.to_bigint().unwrap()) == eval(source).unwrap());
+}
+
+#[test]
+fn async_gather() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+
+	let source: &str = "
+		async fn work(x, ms) {
+			log(\"start \" + x)
+			await sleep(ms)
+			log(\"end \" + x)
+			x
+		}
+		await gather([work(\"a\", 30), work(\"b\", 10), work(\"c\", 20)])
+	";
+
+	let expected: Object = Object::Vec(vec![
+		Object::String(String::from("a")),
+		Object::String(String::from("b")),
+		Object::String(String::from("c")),
+	]);
+	assert!(expected == interpreter.eval(source).unwrap());
+
+	// Every coroutine starts before any of them ends.
+	let mut log: Vec<String> = logged();
+	assert_eq!(vec!["start a", "start b", "start c"], log[..3]);
+	log[3..].sort();
+	assert_eq!(vec!["end a", "end b", "end c"], log[3..]);
+}
+
+#[test]
+fn async_timeout() {
+	let exception: Exception = eval("await timeout(sleep(10000), 10)").err().unwrap();
+	assert_eq!("TimeoutError", exception.except.typer.to_string());
+
+	assert!(Object::Null == eval("await timeout(sleep(1), 1000)").unwrap());
+
+	for source in ["sleep(-1)", "timeout(sleep(1), -1)"] {
+		let exception: Exception = eval(source).err().unwrap();
+		assert_eq!("ValueError", exception.except.typer.to_string());
+	}
+}
+
+fn native_ready_on_second_poll(
+	_: &mut Interpreter, data: &mut Object,
+) -> Result<Option<Object>, Exception> {
+	match data {
+		Object::Boolean(false) => {
+			*data = Object::Boolean(true);
+			Ok(None)
+		},
+		_ => Ok(Some(Object::Integer(42.to_bigint().unwrap()))),
+	}
+}
+
+fn native_pending(_: &mut Interpreter, _: Vec<Object>) -> GLResult {
+	Ok(Object::Future(GFuture::native(
+		"pending",
+		native_ready_on_second_poll,
+		Object::Boolean(false),
+	)))
+}
+
+#[test]
+fn async_native_future() {
+	let mut interpreter: Interpreter = Interpreter::new();
+	interpreter.set_global(
+		"pending",
+		GFunctionNative::new(Some(String::from("pending")), 0, native_pending),
+	);
+
+	let source: &str = "
+		async fn main() { await pending() }
+		await main()
+	";
+	assert!(Object::Integer(42.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+}
+
+#[test]
+fn await_inside_expressions() {
+	let source: &str = "
+		async fn one() { 1 }
+		async fn pair(x) { (x, await one()) }
+		async fn main() {
+			let a = await one() + 1
+			let b = [await one(), 2 * await one()]
+			let c = await pair(await one())
+			(a, b, c)
+		}
+		await main()
+	";
+
+	let expected: Object = Object::Tuple(vec![
+		Object::Integer(2.to_bigint().unwrap()),
+		Object::Vec(vec![
+			Object::Integer(1.to_bigint().unwrap()),
+			Object::Integer(2.to_bigint().unwrap()),
+		]),
+		Object::Tuple(vec![
+			Object::Integer(1.to_bigint().unwrap()),
+			Object::Integer(1.to_bigint().unwrap()),
+		]),
+	]);
+	assert!(expected == eval(source).unwrap());
+
+	let mut interpreter: Interpreter = interpreter_with_log();
+	let source: &str = "
+		fn note(x) { log(x); x }
+		async fn later(x) { await sleep(1); log(x); x }
+		async fn main() { note(\"a\") + await later(\"b\") + note(\"c\") }
+		await main()
+	";
+	assert!(Object::String(String::from("abc")) == interpreter.eval(source).unwrap());
+	assert_eq!(vec!["a", "b", "c"], logged());
+	// A plain function called from a coroutine blocks on its own awaits.
+	let source: &str = "
+		async fn one() { 1 }
+		fn blocking() { await one() }
+		async fn main() { blocking() + 1 }
+		await main()
+	";
+	assert!(Object::Integer(2.to_bigint().unwrap()) == eval(source).unwrap());
+}
+
+#[test]
+fn await_inside_blocks() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	interpreter.set_global("resource", Object::StructRust(StructRust::new(Box::new(Resource))));
+
+	let source: &str = "
+		async fn steps(name) {
+			for step in [1, 2] {
+				log(name)
+				await sleep(1)
+			}
+			name
+		}
+		await gather([steps(\"a\"), steps(\"b\")])
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["a", "b", "a", "b"], logged());
+
+	let source: &str = "
+		async fn main() {
+			with resource as r {
+				defer log(\"defer\")
+				await sleep(1)
+				log(\"body\")
+			}
+		}
+		await main()
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["enter", "body", "defer", "exit false"], logged());
+
+	let source: &str = "
+		async fn fail() { await sleep(1); missing() }
+		async fn main() {
+			with resource as r { await fail() }
+		}
+		await main()
+	";
+	let exception: Exception = interpreter.eval(source).err().unwrap();
+	assert_eq!("NameError", exception.except.typer.to_string());
+	assert_eq!(vec!["enter", "exit true"], logged());
+}
+
+#[test]
+fn await_after_doc_comment() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+
+	let source: &str = "
+		async fn one() { await sleep(1); 1 }
+		async fn step(name) {
+			log(name)
+			/// the value
+			let a = await one()
+			log(name)
+			a
+		}
+		await gather([step(\"a\"), step(\"b\")])
+	";
+	assert_eq!("[1, 1]", interpreter.eval(source).unwrap().to_string());
+	assert_eq!(vec!["a", "b", "a", "b"], logged());
+}
+
+#[test]
+fn set() {
+	let source: &str = "
+		let evens = #{0, 2, 4, 2}
+		let odds = set([1, 3])
+		(evens | odds == set(vec(#{0, 1, 2, 3, 4})), 4 in evens, vec(odds & evens))
+	";
+
+	let expected: Object =
+		Object::Tuple(vec![Object::Boolean(true), Object::Boolean(true), Object::Vec(Vec::new())]);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn comprehension() {
+	let source: &str = "
+		let xs = [1, 2, 3]
+		[x * y for x in xs if x != 2 for y in [10, 100]]
+	";
+
+	let expected: Object = Object::Vec(
+		vec![10, 100, 30, 300]
+			.into_iter()
+			.map(|i| Object::Integer(i.to_bigint().unwrap()))
+			.collect(),
+	);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn comprehension_hashmap_and_set() {
+	let source: &str = "
+		let ages = {\"ana\": 30, \"bia\": 17}
+		let swapped = {age: name for (name, age) in items(ages)}
+		(swapped[17], #{name for name in ages if ages[name] > 18})
+	";
+
+	let expected: Object = Object::Tuple(vec![
+		Object::String(String::from("bia")),
+		Object::Set(vec![Object::String(String::from("ana"))].into_iter().collect()),
+	]);
+	assert!(expected == eval(source).unwrap());
+}
+
+#[test]
+fn comprehension_does_not_leak() {
+	assert!(eval("let v = [x for x in [1, 2]]\nx").is_err());
+}
+
+#[test]
+fn assert_passes() {
+	assert!(Object::Null == eval("assert 1 + 1 == 2\nassert [1]\nnull").unwrap());
+}
+
+#[test]
+fn assert_reports_operands() {
+	let exception: Exception = eval("let xs = [1, 2]\nassert 3 in xs").err().unwrap();
+	assert_eq!("AssertionError", exception.except.typer.to_string());
+	assert_eq!("assert 3 in xs\n  where xs = [1, 2]", exception.except.message);
+
+	let exception: Exception =
+		eval("let a = 2\nassert a * 2 == 5, \"bad \" + \"math\"").err().unwrap();
+	assert_eq!("bad math\n  assert a * 2 == 5\n  where a * 2 = 4", exception.except.message);
+}
+
+#[test]
+fn assert_reports_source_text() {
+	let exception: Exception =
+		eval("let t = (1, 2)\nassert t  ==  ( 2,3 )  // pair").err().unwrap();
+	assert_eq!(
+		"assert t  ==  ( 2,3 )\n  where t = (1, 2)\n    and ( 2,3 ) = (2, 3)",
+		exception.except.message
+	);
+
+	let exception: Exception =
+		eval("let xs = [[1]]\nassert (xs[0][0] +\n\t1) not in [1, 2]").err().unwrap();
+	assert_eq!(
+		"assert (xs[0][0] +\n\t1) not in [1, 2]\n  where (xs[0][0] +\n\t1) = 2",
+		exception.except.message
+	);
+
+	let exception: Exception =
+		eval("let a = 1\nfn f(x) {\n\tassert x  >  3\n}\nf(a)").err().unwrap();
+	assert_eq!("assert x  >  3\n  where x = 1", exception.except.message);
+
+	let exception: Exception = eval("assert [1, 2][0] == 2").err().unwrap();
+	assert_eq!("assert [1, 2][0] == 2\n  where [1, 2][0] = 1", exception.except.message);
+}
+
+thread_local! {
+	static LOG: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn log(_: &mut Interpreter, args: Vec<Object>) -> GLResult {
+	let entry: String = match &args[0] {
+		Object::String(string) => string.clone(),
+		o => o.to_string(),
+	};
+	LOG.with(|log| log.borrow_mut().push(entry));
+	gl_ok!()
+}
+
+fn logged() -> Vec<String> { LOG.with(|log| log.borrow_mut().drain(..).collect()) }
+
+fn interpreter_with_log() -> Interpreter {
+	let mut interpreter: Interpreter = Interpreter::new();
+	interpreter.set_global("log", GFunctionNative::new(Some(String::from("log")), 1, log));
+	interpreter
+}
+
+#[test]
+fn defer() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+
+	let source: &str = "
+		fn work() { defer log(1); defer log(2); log(0); 42 }
+		work()
+	";
+	assert!(Object::Integer(42.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+	assert_eq!(vec!["0", "2", "1"], logged());
+
+	let source: &str = "
+		fn fail() { defer log(\"cleanup\"); missing() }
+		fail()
+	";
+	let exception: Exception = interpreter.eval(source).err().unwrap();
+	assert_eq!("NameError", exception.except.typer.to_string());
+	assert_eq!(vec!["cleanup"], logged());
+
+	let source: &str = "
+		fn each() {
+			defer log(\"done\")
+			for x in [1, 2] { defer log(x); log(\"body\") }
+			for x in [3, 4] { defer log(x); break }
+			log(\"after\")
+		}
+		each()
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["body", "1", "body", "2", "3", "after", "done"], logged());
+
+	let source: &str = "
+		fn each() {
+			for x in [1, 2] { defer log(x); yield x }
+		}
+		for x in each() { log(x * 10) }
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["10", "1", "20", "2"], logged());
+}
+
+#[derive(Clone)]
+struct Resource;
+
+impl ObjectTrait for Resource {
+	fn is_equals(&self, _: &Box<dyn ObjectTrait>) -> bool { false }
+
+	fn enter(&self) -> Option<GLResult> {
+		LOG.with(|log| log.borrow_mut().push(String::from("enter")));
+		None
+	}
+
+	fn exit(&self, exception: Option<&Exception>) -> Result<(), Exception> {
+		LOG.with(|log| log.borrow_mut().push(format!("exit {}", exception.is_some())));
+		Ok(())
+	}
+
+	fn clone(&self) -> Box<dyn ObjectTrait> { Box::new(Resource) }
+}
+
+#[test]
+fn with() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	interpreter.set_global("resource", Object::StructRust(StructRust::new(Box::new(Resource))));
+
+	let source: &str = "with resource as r { defer log(\"defer\"); 42 }";
+	assert!(Object::Integer(42.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+	assert_eq!(vec!["enter", "defer", "exit false"], logged());
+
+	assert!(interpreter.eval("with resource as r { missing() }").is_err());
+	assert_eq!(vec!["enter", "exit true"], logged());
+
+	let exception: Exception = interpreter.eval("with 42 as n {}").err().unwrap();
+	assert_eq!("TypeError", exception.except.typer.to_string());
+
+	let source: &str = "
+		let lock = {\"enter\": fn() { log(\"locked\"); 7 }, \"exit\": fn() { log(\"unlocked\") }}
+		with lock as n { log(n) }
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["locked", "7", "unlocked"], logged());
+
+	let source: &str = "
+		import \"tests/modules/guard.gl\"
+		with guard as g { log(g) }
+	";
+	interpreter.eval(source).unwrap();
+	assert_eq!(vec!["enter", "guarded", "null"], logged());
+
+	let exception: Exception = interpreter.eval("with guard as g { missing() }").err().unwrap();
+	assert_eq!("NameError", exception.except.typer.to_string());
+	assert_eq!(vec![String::from("enter"), exception.except.to_string()], logged());
+}
+
+#[test]
+fn labeled_loops() {
+	let source: &str = "
+		let seen = 0
+		'outer: for row in [[1, 2], [3, 4]] {
+			for cell in row {
+				seen = seen * 10 + cell
+				continue 'outer
+			}
+			seen = seen * 10
+		}
+		seen
+	";
+	assert!(Object::Integer(13.to_bigint().unwrap()) == eval(source).unwrap());
+
+	let source: &str = "
+		let seen = 0
+		'rows: for (x, y) in [(1, 2), (3, 4)] {
+			for cell in [x, y] {
+				seen = seen * 10 + cell
+				break 'rows
+			}
+		}
+		seen
+	";
+	assert!(Object::Integer(1.to_bigint().unwrap()) == eval(source).unwrap());
+}
+
+#[test]
+fn type_annotations() {
+	let source: &str = "
+		fn area(w: Integer, h: Integer) -> Integer { w * h }
+		let size: Integer = area(6, 7)
+		size
+	";
+	assert!(Object::Integer(42.to_bigint().unwrap()) == eval(source).unwrap());
+
+	let exception: Exception =
+		eval("fn area(w: Integer, h) { w * h }\narea(\"6\", 7)").err().unwrap();
+	assert_eq!("TypeError", exception.except.typer.to_string());
+	assert_eq!("argument 'w' of area() must be Integer, not String", exception.except.message);
+
+	let exception: Exception = eval("fn name() -> String { 42 }\nname()").err().unwrap();
+	assert_eq!("return value of name() must be String, not Integer", exception.except.message);
+
+	let exception: Exception = eval("let v: Vec = (1, 2)").err().unwrap();
+	assert_eq!("variable 'v' must be Vec, not Tuple", exception.except.message);
+
+	let exception: Exception = eval("let n: Integer = 1\nn = \"one\"").err().unwrap();
+	assert_eq!("variable 'n' must be Integer, not String", exception.except.message);
+
+	let exception: Exception = eval("fn f(n: Integer) { n = 1.5 }\nf(1)").err().unwrap();
+	assert_eq!("variable 'n' must be Integer, not Float", exception.except.message);
+
+	let source: &str = "
+		let n: Integer = 1
+		let n = \"one\"
+		n = 1.5
+		n
+	";
+	assert_eq!("1.5", eval(source).unwrap().to_string());
+
+	let exception: Exception =
+		eval("async fn name() -> String { 42 }\nawait name()").err().unwrap();
+	assert_eq!("TypeError", exception.except.typer.to_string());
+	assert_eq!("return value of name() must be String, not Integer", exception.except.message);
+
+	let exception: Exception = eval("let n: Integr = 1").err().unwrap();
+	assert_eq!("InvalidSyntax", exception.except.typer.to_string());
+	assert_eq!("unknown type 'Integr'", exception.except.message);
+}
+
+#[test]
+fn module_exports() {
+	let source: &str = "
+		import \"tests/modules/shapes.gl\"
+		shapes::area(2, 3)
+	";
+	assert!(Object::Integer(6.to_bigint().unwrap()) == eval(source).unwrap());
+
+	let exception: Exception =
+		eval("import \"tests/modules/shapes.gl\"\nshapes::scratch").err().unwrap();
+	assert_eq!("AttributeError", exception.except.typer.to_string());
+	assert_eq!("'scratch' is private to module 'shapes'", exception.except.message);
+
+	let names: Object = eval("import \"tests/modules/shapes.gl\"\ndir(shapes)").unwrap();
+	let expected: Object = Object::Vec(vec![
+		Object::String(String::from("area")),
+		Object::String(String::from("unit")),
+	]);
+	assert!(expected == names);
+}
+
+#[test]
+fn module_underscore_names_are_private() {
+	let names: Object = eval("import \"tests/modules/open.gl\"\ndir(open)").unwrap();
+	assert!(Object::Vec(vec![Object::String(String::from("answer"))]) == names);
+	assert!(eval("import \"tests/modules/open.gl\"\nopen::_secret").is_err());
+}
+
+#[test]
+fn import_aliases() {
+	let source: &str = "
+		import \"tests/modules/shapes.gl\" as geometry
+		from \"tests/modules/shapes.gl\" import area, unit as one
+		let big = geometry::area(2, 3)
+		big + area(one, 4)
+	";
+	assert!(Object::Integer(10.to_bigint().unwrap()) == eval(source).unwrap());
+
+	let exception: Exception =
+		eval("from \"tests/modules/shapes.gl\" import scratch").err().unwrap();
+	assert_eq!("ImportError", exception.except.typer.to_string());
+	assert_eq!(
+		"cannot import name 'scratch' from 'tests/modules/shapes.gl'",
+		exception.except.message
+	);
+}
+
+#[test]
+fn import_resolution() {
+	let source: &str = "
+		import \"tests/modules/geometry\"
+		geometry::four
+	";
+	assert!(Object::Integer(4.to_bigint().unwrap()) == eval(source).unwrap());
+
+	let mut interpreter: Interpreter = Interpreter::new();
+	interpreter.add_search_path("tests/modules");
+	let source: &str = "
+		import \"shapes\"
+		shapes::area(2, 3)
+	";
+	assert!(Object::Integer(6.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+
+	let exception: Exception = eval("import \"nowhere\"").err().unwrap();
+	assert_eq!("ImportError", exception.except.typer.to_string());
+	assert!(exception
+		.except
+		.message
+		.starts_with("no module named 'nowhere', tried:\n  nowhere.gl\n"));
+	assert!(exception.except.message.contains("nowhere/mod.gl"));
+}
+
+#[test]
+fn import_runs_modules_once() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	let source: &str = "
+		import \"tests/modules/loud.gl\"
+		import \"tests/modules/shapes.gl\"
+		from \"tests/modules/loud.gl\" import volume
+		import \"tests/modules/../modules/loud.gl\" as again
+		let area = shapes::area(volume, again::volume)
+		area
+	";
+	assert!(Object::Integer(121.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+	assert_eq!(vec![String::from("loading")], logged());
+}
+
+#[test]
+fn circular_import() {
+	let exception: Exception = eval("import \"tests/modules/cycle_a.gl\"").err().unwrap();
+	assert_eq!("ImportError", exception.except.typer.to_string());
+	assert_eq!(
+		"circular import: tests/modules/cycle_a.gl -> tests/modules/cycle_b.gl -> \
+		 tests/modules/cycle_a.gl",
+		exception.except.message
+	);
+
+	let exception: Exception = Interpreter::new().script("tests/modules/cycle_b.gl").err().unwrap();
+	assert_eq!("ImportError", exception.except.typer.to_string());
+	assert_eq!(
+		"circular import: tests/modules/cycle_b.gl -> tests/modules/cycle_a.gl -> \
+		 tests/modules/cycle_b.gl",
+		exception.except.message
+	);
+}
+
+#[test]
+fn import_main_script() {
+	let mut interpreter: Interpreter = interpreter_with_log();
+	interpreter.script("tests/modules/loud.gl").unwrap();
+	assert_eq!(vec![String::from("loading")], logged());
+
+	let source: &str = "
+		import \"tests/modules/loud.gl\"
+		loud::volume
+	";
+	assert!(Object::Integer(11.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+	assert!(logged().is_empty());
+}
+
+#[test]
+fn import_errors_propagate() {
+	let source: &str = "
+		let before = 1
+		import \"tests/modules/broken_runtime.gl\"
+	";
+	let exception: Exception = eval(source).err().unwrap();
+	assert_eq!("ImportError", exception.except.typer.to_string());
+	assert_eq!("failed to import 'tests/modules/broken_runtime.gl'", exception.except.message);
+	let importer: &ExceptionPoint = exception.exception_points.last().unwrap();
+	assert_eq!(("eval", 2), (importer.module_context.as_str(), importer.position.line));
+
+	let cause: &Exception = exception.cause.as_ref().unwrap();
+	assert_eq!("NameError", cause.except.typer.to_string());
+	let module: &ExceptionPoint = cause.exception_points.last().unwrap();
+	assert_eq!(
+		("tests/modules/broken_runtime.gl", 2),
+		(module.module_context.as_str(), module.position.line)
+	);
+
+	let exception: Exception = eval("import \"tests/modules/broken_syntax.gl\"").err().unwrap();
+	let cause: &Exception = exception.cause.as_ref().unwrap();
+	assert_eq!("InvalidSyntax", cause.except.typer.to_string());
+	assert_eq!("tests/modules/broken_syntax.gl", cause.exception_points[0].module_context);
+
+	let exception: Exception = Interpreter::new().script("tests/modules/missing.gl").err().unwrap();
+	assert!(exception.except.message.starts_with("can't open file 'tests/modules/missing.gl'"));
+}
+
+#[test]
+fn import_registered_module() {
+	let mut context: HashMap<String, Object> = HashMap::new();
+	context.insert(String::from("answer"), Object::Integer(42.to_bigint().unwrap()));
+
+	let mut interpreter: Interpreter = Interpreter::new();
+	interpreter.register_module(GModuleNative::new(String::from("consts"), context));
+
+	let source: &str = "
+		import \"consts\"
+		from \"consts\" import answer as a
+		let b = consts::answer
+		a + b
+	";
+	assert!(Object::Integer(84.to_bigint().unwrap()) == interpreter.eval(source).unwrap());
+}
+
+#[test]
+fn chars() {
+	assert!(Object::Char('b') == eval("\"abc\"[1]").unwrap());
+	assert!(
+		Object::Vec(vec![Object::Char('h'), Object::Char('é')]) == eval("vec(\"hé\")").unwrap()
+	);
+	assert!(Object::Boolean(true) == eval("'a' < 'b'").unwrap());
+	assert!(Object::Boolean(true) == eval("'é' in \"café\"").unwrap());
+	assert!(Object::Integer(97.to_bigint().unwrap()) == eval("ord('a')").unwrap());
+	assert!(Object::Char('\u{1F600}') == eval("chr(128512)").unwrap());
+
+	let exception: Exception = eval("\"abc\"[3]").err().unwrap();
+	assert_eq!("IndexError", exception.except.typer.to_string());
+	let exception: Exception = eval("chr(55296)").err().unwrap();
+	assert_eq!("ValueError", exception.except.typer.to_string());
+}
+
+#[test]
+fn bytes() {
+	let frame: Object = Object::Bytes(vec![0x00, 0xff, b'o', b'k']);
+	assert!(frame == eval("b\"\\x00\\xff\" + b\"ok\"").unwrap());
+	assert!(Object::Integer(255.to_bigint().unwrap()) == eval("b\"\\x00\\xff\"[1]").unwrap());
+	assert!(Object::Bytes(b"ok".to_vec()) == eval("b\"\\x00ok\"[1:]").unwrap());
+	assert!(Object::Boolean(true) == eval("b\"ok\" in b\"book\"").unwrap());
+	assert_eq!("b\"\\x00\\\"\"", eval("b\"\\x00\\\"\"").unwrap().to_string());
+
+	let source: &str = "
+		let table = {b\"key\": 1}
+		table[encode(\"key\", \"ascii\")]
+	";
+	assert!(Object::Integer(1.to_bigint().unwrap()) == eval(source).unwrap());
+
+	assert!(Object::Bytes(vec![0xc3, 0xa9]) == eval("encode(\"é\", \"utf-8\")").unwrap());
+	assert!(Object::String(String::from("é")) == eval("decode(b\"\\xe9\", \"latin-1\")").unwrap());
+	assert!(Object::String(String::from("bc")) == eval("\"abc\"[1:5]").unwrap());
+
+	let exception: Exception = eval("decode(b\"\\xff\", \"utf-8\")").err().unwrap();
+	assert_eq!("ValueError", exception.except.typer.to_string());
+	let exception: Exception = eval("encode(\"é\", \"ebcdic\")").err().unwrap();
+	assert_eq!("unknown encoding: ebcdic", exception.except.message);
+}
+
+#[test]
+fn number_literals() {
+	assert!(Object::Integer(255.to_bigint().unwrap()) == eval("0xff").unwrap());
+	assert!(Object::Integer(1_000_010.to_bigint().unwrap()) == eval("1_000_000 + 0b1010").unwrap());
+
+	let avogadro: BigInt = BigInt::parse_bytes(b"602000000000000000000000", 10).unwrap();
+	assert!(Object::Float(BigRational::from_integer(avogadro)) == eval("6.02e23").unwrap());
+	assert!(Object::Float(BigRational::new(1.into(), 1000.into())) == eval("1e-3").unwrap());
+
+	// big_rational_to_string output reads back as the same number.
+	let third: Object = eval("1.0 / 3").unwrap();
+	assert_eq!("0.(3)", third.to_string());
+	assert!(third == eval("0.(3)").unwrap());
+}
+
+#[test]
+fn doc_comments() {
+	let source: &str = "
+		/// Adds one.
+		fn inc(n: Integer) -> Integer { n + 1 }
+		help(inc)
+	";
+	let expected: Object =
+		Object::String(String::from("fn inc(n: Integer) -> Integer\n\nAdds one."));
+	assert!(expected == eval(source).unwrap());
+
+	let help: Object = eval("import \"tests/modules/shapes.gl\"\nhelp(shapes)").unwrap();
+	let expected: &str = "module shapes\n\nAreas of plane shapes.\n\narea\n    Area of a `w` by \
+	                      `h` rectangle.\n\nunit";
+	assert!(Object::String(String::from(expected)) == help);
+
+	let exception: Exception = eval("help(1)").err().unwrap();
+	assert_eq!("help() argument must be Fn or Module, not Integer", exception.except.message);
+}
+
+#[test]
+fn comments() {
+	let source: &str = "
+		/* a /* nested */ block */
+		let add = fn(a, b) { a + b } // a line comment
+		add(1, // one
+			2 /* two */)
+	";
+	assert!(Object::Integer(BigInt::from(3)) == eval(source).unwrap());
+}
+
+#[test]
+fn shebang_and_bom() {
+	let source: &str = "\u{feff}#!/usr/bin/env gl\nlet x = 1\nx + 1";
+	assert!(Object::Integer(BigInt::from(2)) == eval(source).unwrap());
+
+	let exception: Exception = eval("#!/usr/bin/env gl\n\nmissing").err().unwrap();
+	assert_eq!(2, exception.exception_points.last().unwrap().position.line);
+}
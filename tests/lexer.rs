@@ -264,3 +264,373 @@ fn run_keywords() {
 	assert_eq!(false, rtokens.is_err());
 	assert_eq!(expected_tokens, rtokens.unwrap())
 }
+
+#[test]
+fn run_set() {
+	let source: Source = Source::from_string("#{1} | & ^");
+	let module: &str = "tests/lexer/set";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(HashBrace, (0, 0), (2, 0)),
+		(INTEGER(format!("1")), (2, 0), (3, 0)),
+		(RightBrace, (3, 0), (4, 0)),
+		(PIPE, (5, 0), (6, 0)),
+		(AMPERSAND, (7, 0), (8, 0)),
+		(CARET, (9, 0), (10, 0)),
+		(EOF, (10, 0), (10, 0)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap())
+}
+
+#[test]
+fn run_char() {
+	let source: Source = Source::from_string("'a' '\\n' '\\'' 'outer");
+	let module: &str = "tests/lexer/char";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(CHAR('a'), (0, 0), (3, 0)),
+		(CHAR('\n'), (4, 0), (8, 0)),
+		(CHAR('\''), (9, 0), (13, 0)),
+		(LABEL(format!("outer")), (14, 0), (20, 0)),
+		(EOF, (20, 0), (20, 0)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+
+	let mut lexer: Lexer = Lexer::new(Source::from_string("''"), module);
+	assert_eq!("empty char literal", lexer.run().err().unwrap().except.message);
+
+	let mut lexer: Lexer = Lexer::new(Source::from_string("let c = 'ab'"), module);
+	let exception: Exception = lexer.run().err().unwrap();
+	assert_eq!("char literal must be one character", exception.except.message);
+	assert_eq!(Position::new(8, 0), exception.exception_points[0].position);
+}
+
+#[test]
+fn run_bytes() {
+	let source: Source = Source::from_string("b\"a\\x00\\xff\" b");
+	let module: &str = "tests/lexer/bytes";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(BYTES(vec![b'a', 0x00, 0xff]), (0, 0), (12, 0)),
+		(IDENTIFIER(format!("b")), (13, 0), (14, 0)),
+		(EOF, (14, 0), (14, 0)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+
+	let mut lexer: Lexer = Lexer::new(Source::from_string("b\"é\""), module);
+	assert_eq!(true, lexer.run().is_err());
+}
+
+#[test]
+fn run_string_escapes() {
+	let source: Source = Source::from_string("\"\\u{1F600}\\x41\\0\\'\" \"a\\\n     b\" x");
+	let module: &str = "tests/lexer/string_escapes";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(STRING(format!("\u{1F600}A\0'")), (0, 0), (19, 0)),
+		(STRING(format!("ab")), (20, 0), (7, 1)),
+		(IDENTIFIER(format!("x")), (8, 1), (9, 1)),
+		(EOF, (9, 1), (9, 1)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+}
+
+#[test]
+fn run_string_escape_errors() {
+	let cases: Vec<(&str, &str)> = vec![
+		("\"ok \\u{D800}\"", "invalid unicode escape: D800 is a surrogate"),
+		("\"ok \\u{110000}\"", "invalid unicode escape: 110000 is past 10FFFF"),
+		("\"ok \\u{}\"", "\\u escape must be \\u{ followed by 1 to 6 hex digits and }"),
+		("\"ok \\x80\"", "out of range hex escape: \\x80 (must be at most \\x7f)"),
+		("\"ok \\xZ0\"", "\\x escape must be followed by two hex digits"),
+		("\"ok \\q\"", "unknown character escape: `q`"),
+	];
+
+	for (source, message) in cases {
+		let mut lexer: Lexer = Lexer::new(Source::from_string(source), "tests/lexer/escapes");
+		let exception: Exception = lexer.run().err().unwrap();
+
+		assert_eq!(message, exception.except.message);
+		assert_eq!(Position::new(4, 0), exception.exception_points[0].position);
+	}
+}
+
+#[test]
+fn run_raw_and_triple_quoted_strings() {
+	let source: Source = Source::from_string(
+		"r\"C:\\dir\\n\" r##\"say \"#hi\"##\n\"\"\"\n    SELECT *\n      FROM t\\twhere\n    \
+		 \"\"\" \"\"",
+	);
+	let module: &str = "tests/lexer/raw_and_triple";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(STRING(format!("C:\\dir\\n")), (0, 0), (11, 0)),
+		(STRING(format!("say \"#hi")), (12, 0), (27, 0)),
+		(NEWLINE, (27, 0), (28, 0)),
+		(STRING(format!("SELECT *\n  FROM t\twhere")), (0, 1), (7, 4)),
+		(STRING(format!("")), (8, 4), (10, 4)),
+		(EOF, (10, 4), (10, 4)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+
+	let mut lexer: Lexer = Lexer::new(Source::from_string("r#\"open\""), module);
+	assert_eq!("unterminated raw string", lexer.run().err().unwrap().except.message);
+}
+
+#[test]
+fn run_number_forms() {
+	let source: Source = Source::from_string("0xFF 0b1010 0o17 1_000_000 6.02e23 1e-3 0.(3)");
+	let module: &str = "tests/lexer/number_forms";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(INTEGER(format!("0xFF")), (0, 0), (4, 0)),
+		(INTEGER(format!("0b1010")), (5, 0), (11, 0)),
+		(INTEGER(format!("0o17")), (12, 0), (16, 0)),
+		(INTEGER(format!("1000000")), (17, 0), (26, 0)),
+		(FLOAT(format!("6.02e23")), (27, 0), (34, 0)),
+		(FLOAT(format!("1e-3")), (35, 0), (39, 0)),
+		(FLOAT(format!("0.(3)")), (40, 0), (45, 0)),
+		(EOF, (45, 0), (45, 0)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+
+	// A dot not followed by a digit ends the number.
+	for (source, expected) in [
+		("1.e5", vec![INTEGER(String::from("1")), DOT, IDENTIFIER(String::from("e5")), EOF]),
+		("1.", vec![INTEGER(String::from("1")), DOT, EOF]),
+		("1._5", vec![INTEGER(String::from("1")), DOT, IDENTIFIER(String::from("_5")), EOF]),
+		("1.5e2", vec![FLOAT(String::from("1.5e2")), EOF]),
+	] {
+		let mut lexer: Lexer = Lexer::new(Source::from_string(source), module);
+		let typers: Vec<TokenType> =
+			lexer.run().unwrap().into_iter().map(|token| token.typer).collect();
+		assert_eq!(expected, typers);
+	}
+
+	for (source, message) in vec![
+		("0b102", "invalid digit '2' in base 2 literal"),
+		("0x", "expected digits after '0x'"),
+		("1e+", "expected exponent digits"),
+		("1e99999", "exponent is too large"),
+	] {
+		let mut lexer: Lexer = Lexer::new(Source::from_string(source), module);
+		assert_eq!(message, lexer.run().err().unwrap().except.message);
+	}
+}
+
+#[test]
+fn run_doc_comments() {
+	let source: Source = Source::from_string("//! Module.\n/// Item.\n//// rule");
+	let module: &str = "tests/lexer/doc_comments";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(ModuleDocComment(format!("Module.")), (0, 0), (11, 0)),
+		(NEWLINE, (11, 0), (12, 0)),
+		(DocComment(format!("Item.")), (0, 1), (9, 1)),
+		(NEWLINE, (9, 1), (10, 1)),
+		(EOF, (9, 2), (9, 2)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+}
+
+#[test]
+fn run_comments() {
+	let source: Source =
+		Source::from_string("f(1, // one\n2 /* two /* nested */ */, 3) /*\n*/ * /");
+	let module: &str = "tests/lexer/comments";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(IDENTIFIER(format!("f")), (0, 0), (1, 0)),
+		(LeftParen, (1, 0), (2, 0)),
+		(INTEGER(format!("1")), (2, 0), (3, 0)),
+		(COMMA, (3, 0), (4, 0)),
+		(NEWLINE, (11, 0), (12, 0)),
+		(INTEGER(format!("2")), (0, 1), (1, 1)),
+		(COMMA, (24, 1), (25, 1)),
+		(INTEGER(format!("3")), (26, 1), (27, 1)),
+		(RightParen, (27, 1), (28, 1)),
+		(ASTERISK, (3, 2), (4, 2)),
+		(SLASH, (5, 2), (6, 2)),
+		(EOF, (6, 2), (6, 2)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+}
+
+#[test]
+fn run_unterminated_block_comment() {
+	let source: Source = Source::from_string("1\n  /* a /* b */\n");
+	let module: &str = "tests/lexer/unterminated_block_comment";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let exception: Exception = lexer.run().err().unwrap();
+	assert_eq!("unterminated block comment", exception.except.message);
+	assert_eq!(1, exception.exception_points[0].position.line);
+	assert_eq!(2, exception.exception_points[0].position.column);
+}
+
+#[test]
+fn run_shebang_and_bom() {
+	let source: Source = Source::from_string("\u{feff}#!/usr/bin/env gl\nlet");
+	let module: &str = "tests/lexer/shebang_and_bom";
+	let mut lexer: Lexer = Lexer::new(source, module);
+
+	let expected_tokens: Vec<Token> = vec_tokens_positions2vec_token(vec![
+		(NEWLINE, (17, 0), (18, 0)),
+		(LET, (0, 1), (3, 1)),
+		(EOF, (3, 1), (3, 1)),
+	]);
+	let rtokens: Result<Vec<Token>, Exception> = lexer.run();
+
+	assert_eq!(false, rtokens.is_err());
+	assert_eq!(expected_tokens, rtokens.unwrap());
+
+	// Only the first line may be a shebang.
+	let mut lexer: Lexer = Lexer::new(Source::from_string("1\n#!/usr/bin/env gl"), module);
+	assert_eq!("invalid character '#'", lexer.run().err().unwrap().except.message);
+}
+
+#[test]
+fn run_lossless_trivia() {
+	let source: Source = Source::from_string("let x = 1 // one\n\t/* two */ x\n");
+	let module: &str = "tests/lexer/lossless_trivia";
+	let mut lexer: Lexer = Lexer::new_lossless(source, module);
+
+	let tokens: Vec<Token> = lexer.run().unwrap();
+	let trivia: Vec<(&str, &str, &str)> = tokens
+		.iter()
+		.map(|token| {
+			(token.leading_trivia.as_str(), token.text.as_str(), token.trailing_trivia.as_str())
+		})
+		.collect();
+
+	assert_eq!(
+		vec![
+			("", "let", " "),
+			("", "x", " "),
+			("", "=", " "),
+			("", "1", " // one"),
+			("", "\n", ""),
+			("\t/* two */ ", "x", ""),
+			("", "\n", ""),
+			("", "", ""),
+		],
+		trivia
+	);
+}
+
+#[test]
+fn run_lossless_round_trip() {
+	let mut sources: Vec<String> = vec![
+		String::from(""),
+		String::from("  \t\r\n\t "),
+		String::from("{\"name\": \"José\", \"age\": 17}"),
+		String::from("+-*==/!==!><>=<="),
+		String::from(".,;:()[]{}"),
+		String::from("'a' '\\n' 'outer: b\"\\x00\" r#\"raw\"# \"\\u{1F600}\\\n   next\""),
+		String::from("let s = \"\"\"\n\t\tindented\n\t\"\"\"\r\n0xff_ff 1_000 0.(3) 1.5e-3"),
+		String::from("//! Module.\n/// Item.\n//// rule\nfn f() { /* a /* b */ */ }  "),
+		String::from("\u{feff}#!/usr/bin/env gl\nlet x = 1"),
+	];
+
+	let mut paths: Vec<std::path::PathBuf> = Vec::new();
+	let mut directories: Vec<std::path::PathBuf> = vec![std::path::PathBuf::from("tests/modules")];
+	while let Some(directory) = directories.pop() {
+		for entry in std::fs::read_dir(directory).unwrap() {
+			let path: std::path::PathBuf = entry.unwrap().path();
+			match path.is_dir() {
+				true => directories.push(path),
+				false => paths.push(path),
+			}
+		}
+	}
+	sources.extend(paths.iter().map(|path| std::fs::read_to_string(path).unwrap()));
+
+	// Every string literal of the lexer and parser tests, which holds every
+	// source they lex.
+	let literal: regex::Regex = regex::Regex::new(r#""((?:[^"\\]|\\(?s:.))*)""#).unwrap();
+	for path in ["tests/lexer.rs", "tests/parser.rs"] {
+		let text: String = std::fs::read_to_string(path).unwrap();
+		sources.extend(literal.captures_iter(&text).map(|captures| unescape(&captures[1])));
+	}
+
+	for source in sources {
+		let module: &str = "tests/lexer/round_trip";
+
+		if Lexer::new(Source::from_string(source.clone()), module).run().is_err() {
+			continue;
+		}
+
+		let mut lexer: Lexer = Lexer::new_lossless(Source::from_string(source.clone()), module);
+		let tokens: Vec<Token> = lexer.run().unwrap();
+
+		assert_eq!(source, tokens.iter().map(Token::to_source).collect::<String>());
+	}
+}
+
+/// The value of the body of a Rust string literal.
+fn unescape(literal: &str) -> String {
+	let mut value: String = String::new();
+	let mut chars = literal.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			value.push(c);
+			continue;
+		}
+
+		match chars.next() {
+			Some('n') => value.push('\n'),
+			Some('t') => value.push('\t'),
+			Some('r') => value.push('\r'),
+			Some('0') => value.push('\0'),
+			Some('x') => {
+				let hex: String = chars.by_ref().take(2).collect();
+				value.push(u8::from_str_radix(&hex, 16).unwrap() as char);
+			},
+			Some('u') => {
+				let hex: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+				value.push(char::from_u32(u32::from_str_radix(&hex, 16).unwrap()).unwrap());
+			},
+			Some('\n') =>
+				while chars.peek().is_some_and(|c| c.is_whitespace()) {
+					chars.next();
+				},
+			Some(c) => value.push(c),
+			None => {},
+		}
+	}
+
+	value
+}
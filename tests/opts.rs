@@ -147,3 +147,48 @@ fn boolean() {
 #[test]
 #[should_panic]
 fn boolean_divide_by_zero() { (Object::Boolean(true) / Object::Boolean(false)).unwrap(); }
+
+#[test]
+fn contains() {
+	let vector = Object::Vec(vec![Object::Integer(42.to_bigint().unwrap()), Object::Null]);
+	assert!(vector.contains(&Object::Integer(42.to_bigint().unwrap())).unwrap());
+	assert!(!vector.contains(&Object::Boolean(true)).unwrap());
+
+	let tuple = Object::Tuple(vec![Object::String(String::from("text"))]);
+	assert!(tuple.contains(&Object::String(String::from("text"))).unwrap());
+
+	let mut hashmap: HashMap<Object, Object> = HashMap::new();
+	hashmap.insert(Object::String(String::from("key")), Object::Null);
+	let hashmap = Object::HashMap(hashmap);
+	assert!(hashmap.contains(&Object::String(String::from("key"))).unwrap());
+	assert!(!hashmap.contains(&Object::Null).unwrap());
+
+	let string = Object::String(String::from("Hello World"));
+	assert!(string.contains(&Object::String(String::from("lo W"))).unwrap());
+	assert!(!string.contains(&Object::String(String::from("world"))).unwrap());
+	assert!(string.contains(&Object::Integer(42.to_bigint().unwrap())).is_err());
+
+	assert!(Object::Integer(42.to_bigint().unwrap()).contains(&Object::Null).is_err());
+}
+
+fn set(values: Vec<i32>) -> Object {
+	Object::Set(values.into_iter().map(|i| Object::Integer(i.to_bigint().unwrap())).collect())
+}
+
+#[test]
+fn set_algebra() {
+	let union = (set(vec![1, 2]) | set(vec![2, 3])).unwrap();
+	assert!(union == set(vec![1, 2, 3]));
+
+	let intersection = (set(vec![1, 2]) & set(vec![2, 3])).unwrap();
+	assert!(intersection == set(vec![2]));
+
+	let difference = (set(vec![1, 2]) - set(vec![2, 3])).unwrap();
+	assert!(difference == set(vec![1]));
+
+	let symmetric_difference = (set(vec![1, 2]) ^ set(vec![2, 3])).unwrap();
+	assert!(symmetric_difference == set(vec![1, 3]));
+
+	assert!(set(vec![1, 2]).contains(&Object::Integer(2.to_bigint().unwrap())).unwrap());
+	assert!((set(vec![1]) | Object::Vec(Vec::new())).is_err());
+}
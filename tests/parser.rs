@@ -196,6 +196,7 @@ fn run_let() {
 
 	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Let(
 		format!("universo"),
+		None,
 		BigInt::parse_bytes(b"42", 10).unwrap().into(),
 	)]);
 	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
@@ -215,7 +216,9 @@ fn run_fn_statement() {
 	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Fn {
 		name: String::from("main"),
 		params: Vec::new(),
-		body: Block(Vec::new()),
+		returns: None,
+		body: Block::new(Vec::new()),
+		is_async: false,
 	}]);
 	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
 
@@ -234,7 +237,9 @@ fn run_fn_expression() {
 	let expected_ast: AbstractSyntaxTree =
 		vec_statements2ast(vec![Statement::ExpressionReturn(Expression::Fn {
 			params: Vec::new(),
-			body: Block(Vec::new()),
+			returns: None,
+			body: Block::new(Vec::new()),
+			is_async: false,
 		})]);
 	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
 
@@ -251,9 +256,377 @@ fn run_import() {
 	assert_eq!(false, rparser.is_err());
 
 	let expected_ast: AbstractSyntaxTree =
-		vec_statements2ast(vec![Statement::Import(String::from("mylib"))]);
+		vec_statements2ast(vec![Statement::Import(String::from("mylib"), None)]);
 	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
 
 	assert_eq!(false, rast.is_err());
 	assert_eq!(expected_ast, rast.unwrap());
 }
+
+#[test]
+fn run_in() {
+	let source: Source = Source::from_string("42 in [42]; 42 not in [42]");
+	let module: &str = "tests/parser/in";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![
+		Statement::Expression(Expression::Infix(
+			Infix::In,
+			Box::new(42.to_bigint().unwrap().into()),
+			Box::new(Expression::Literal(Literal::Vec(vec![42.to_bigint().unwrap().into()]))),
+		)),
+		Statement::ExpressionReturn(Expression::Infix(
+			Infix::NotIn,
+			Box::new(42.to_bigint().unwrap().into()),
+			Box::new(Expression::Literal(Literal::Vec(vec![42.to_bigint().unwrap().into()]))),
+		)),
+	]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_yield() {
+	let source: Source = Source::from_string("fn gen() { yield 42; yield }");
+	let module: &str = "tests/parser/yield";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Fn {
+		name: String::from("gen"),
+		params: Vec::new(),
+		returns: None,
+		body: Block::new(vec![
+			Statement::Yield(42.to_bigint().unwrap().into()),
+			Statement::Yield(Expression::Literal(Literal::Null)),
+		]),
+		is_async: false,
+	}]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_async() {
+	let source: Source = Source::from_string("async fn main() { await sleep(1) }");
+	let module: &str = "tests/parser/async";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Fn {
+		name: String::from("main"),
+		params: Vec::new(),
+		returns: None,
+		body: Block::new(vec![Statement::ExpressionReturn(Expression::Await(Box::new(
+			Expression::Call {
+				function: Box::new(Expression::Identifier(String::from("sleep"))),
+				arguments: vec![1.to_bigint().unwrap().into()],
+			},
+		)))]),
+		is_async: true,
+	}]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_set() {
+	let source: Source = Source::from_string("#{42, \"text\"}");
+	let module: &str = "tests/parser/set";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree =
+		vec_statements2ast(vec![Statement::ExpressionReturn(Expression::Literal(Literal::Set(
+			vec![42.to_bigint().unwrap().into(), Expression::Literal(format!("text").into())],
+		)))]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_vec_comprehension() {
+	let source: Source = Source::from_string("[x for x in xs if x]");
+	let module: &str = "tests/parser/vec_comprehension";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::ExpressionReturn(
+		Expression::Literal(Literal::VecComprehension(
+			Box::new(Expression::Identifier(String::from("x"))),
+			vec![ComprehensionFor {
+				targets: vec![String::from("x")],
+				iter: Expression::Identifier(String::from("xs")),
+				conditions: vec![Expression::Identifier(String::from("x"))],
+			}],
+		)),
+	)]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_assert() {
+	let source: Source = Source::from_string("assert x == 1, \"x is not one\"");
+	let module: &str = "tests/parser/assert";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Assert(
+		Expression::Infix(
+			Infix::Equal,
+			Box::new(Expression::Identifier(String::from("x"))),
+			Box::new(1.to_bigint().unwrap().into()),
+		),
+		Some(Expression::Literal(Literal::String(String::from("x is not one")))),
+		String::from("x == 1"),
+	)]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_defer_and_with() {
+	let source: Source = Source::from_string("with open() as file { defer close(file) }");
+	let module: &str = "tests/parser/with";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::With {
+		value: Expression::Call {
+			function: Box::new(Expression::Identifier(String::from("open"))),
+			arguments: Vec::new(),
+		},
+		name: String::from("file"),
+		body: Block::new(vec![Statement::Defer(Expression::Call {
+			function: Box::new(Expression::Identifier(String::from("close"))),
+			arguments: vec![Expression::Identifier(String::from("file"))],
+		})]),
+	}]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_labeled_loops() {
+	let source: Source = Source::from_string("'outer: for x in xs { for y in x { break 'outer } }");
+	let module: &str = "tests/parser/labeled_loops";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::For {
+		label: Some(String::from("outer")),
+		targets: vec![String::from("x")],
+		iter: Expression::Identifier(String::from("xs")),
+		body: Block::new(vec![Statement::For {
+			label: None,
+			targets: vec![String::from("y")],
+			iter: Expression::Identifier(String::from("x")),
+			body: Block::new(vec![Statement::Break(Some(String::from("outer")))]),
+		}]),
+	}]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_undeclared_label() {
+	let sources: Vec<&str> = vec![
+		"'outer: for x in xs { break 'inner }",
+		"'outer: for x in xs { fn f() { for y in x { continue 'outer } } }",
+	];
+
+	for source in sources {
+		let lexer: Lexer = Lexer::new(Source::from_string(source), "tests/parser/label");
+		let exception: Exception = Parser::new(lexer).unwrap().run().err().unwrap();
+		let column: usize = source.rfind('\'').unwrap();
+
+		assert_eq!("InvalidSyntax", exception.except.typer.to_string());
+		assert_eq!(column, exception.exception_points[0].position.column);
+	}
+
+	let lexer: Lexer = Lexer::new(Source::from_string("break"), "tests/parser/label");
+	assert!(Parser::new(lexer).unwrap().run().is_err());
+}
+
+#[test]
+fn run_await_restrictions() {
+	let sources: Vec<(&str, &str)> = vec![
+		("async fn f(xs) { [await g(x) for x in xs] }", "for"),
+		("async fn f(xs) { let m = {x: await g(x) for x in xs} }", "for"),
+		("async fn f(xs) { #{x for x in xs if await g(x)} }", "for"),
+		("async fn f() { defer await g() }", "await"),
+		("async fn f(x) { assert x, await g() }", "await"),
+	];
+
+	for (source, at) in sources {
+		let lexer: Lexer = Lexer::new(Source::from_string(source), "tests/parser/await");
+		let exception: Exception = Parser::new(lexer).unwrap().run().err().unwrap();
+
+		assert_eq!("InvalidSyntax", exception.except.typer.to_string());
+		assert_eq!(source.find(at).unwrap(), exception.exception_points[0].position.column);
+	}
+
+	let sources: Vec<&str> = vec![
+		"fn f(xs) { [await g(x) for x in xs] }",
+		"async fn f(xs) { let h = fn() { defer await g() } }",
+		"async fn f(xs) { let a = [await g(x), 1] }",
+	];
+
+	for source in sources {
+		let lexer: Lexer = Lexer::new(Source::from_string(source), "tests/parser/await");
+		assert!(Parser::new(lexer).unwrap().run().is_ok());
+	}
+}
+
+#[test]
+fn run_type_annotations() {
+	let source: Source =
+		Source::from_string("fn area(w: Integer, h) -> Integer { w * h }; let v: Vec = []");
+	let module: &str = "tests/parser/type_annotations";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![
+		Statement::Fn {
+			name: String::from("area"),
+			params: vec![
+				Param { name: String::from("w"), typer: Some(String::from("Integer")) },
+				Param { name: String::from("h"), typer: None },
+			],
+			returns: Some(String::from("Integer")),
+			body: Block::new(vec![Statement::ExpressionReturn(Expression::Infix(
+				Infix::Multiply,
+				Box::new(Expression::Identifier(String::from("w"))),
+				Box::new(Expression::Identifier(String::from("h"))),
+			))]),
+			is_async: false,
+		},
+		Statement::Let(
+			String::from("v"),
+			Some(String::from("Vec")),
+			Expression::Literal(Literal::Vec(Vec::new())),
+		),
+	]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_export() {
+	let source: Source = Source::from_string("export let _x = 1");
+	let module: &str = "tests/parser/export";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![Statement::Export(Box::new(
+		Statement::Let(String::from("_x"), None, 1.to_bigint().unwrap().into()),
+	))]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_import_forms() {
+	let source: Source =
+		Source::from_string("import \"lib/utils.gl\" as u\nfrom \"lib/utils.gl\" import a, b as c");
+	let module: &str = "tests/parser/import_forms";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![
+		Statement::Import(String::from("lib/utils.gl"), Some(String::from("u"))),
+		Statement::ImportFrom(String::from("lib/utils.gl"), vec![
+			(String::from("a"), None),
+			(String::from("b"), Some(String::from("c"))),
+		]),
+	]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_slice() {
+	let source: Source = Source::from_string("data[1:]\ndata[:n]");
+	let module: &str = "tests/parser/slice";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let data: Box<Expression> = Box::new(Expression::Identifier(String::from("data")));
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![
+		Statement::ExpressionReturn(Expression::Slice(
+			data.clone(),
+			Some(Box::new(1.to_bigint().unwrap().into())),
+			None,
+		)),
+		Statement::ExpressionReturn(Expression::Slice(
+			data,
+			None,
+			Some(Box::new(Expression::Identifier(String::from("n")))),
+		)),
+	]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+}
+
+#[test]
+fn run_doc_comments() {
+	let source: Source = Source::from_string("//! Tools.\n\n/// Doubles.\n/// Exactly.\nlet x = 2");
+	let module: &str = "tests/parser/doc_comments";
+	let lexer: Lexer = Lexer::new(source, module);
+	let rparser: Result<Parser, Exception> = Parser::new(lexer);
+	assert_eq!(false, rparser.is_err());
+
+	let expected_ast: AbstractSyntaxTree = vec_statements2ast(vec![
+		Statement::ModuleDoc(String::from("Tools.")),
+		Statement::Doc(
+			String::from("Doubles.\nExactly."),
+			Box::new(Statement::Let(String::from("x"), None, 2.to_bigint().unwrap().into())),
+		),
+	]);
+	let rast: Result<AbstractSyntaxTree, Exception> = rparser.unwrap().run();
+
+	assert_eq!(false, rast.is_err());
+	assert_eq!(expected_ast, rast.unwrap());
+
+	let lexer: Lexer = Lexer::new(Source::from_string("/// Lost.\n1 + 1"), module);
+	let exception: Exception = Parser::new(lexer).unwrap().run().err().unwrap();
+	assert_eq!("expected 'fn', 'let' or 'export' after doc comment", exception.except.message);
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 the GLanguage authors. All rights reserved. MIT license.
+
+extern crate gl_core;
+
+use std::io::Read;
+
+use gl_core::preludes::*;
+
+/// Hands out its bytes one at a time, splitting every multi-byte character
+/// across reads.
+struct Trickle(Vec<u8>);
+
+impl Read for Trickle {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		if self.0.is_empty() || buf.is_empty() {
+			return Ok(0);
+		}
+
+		buf[0] = self.0.remove(0);
+		Ok(1)
+	}
+}
+
+fn read_all(source: &mut Source) -> Result<String, Exception> {
+	let mut content: String = String::new();
+
+	while let Some(c) = source.next_char()? {
+		content.push(c);
+	}
+
+	Ok(content)
+}
+
+#[test]
+fn from_string() {
+	let mut source: Source = Source::from_string("José 😀\n");
+	assert_eq!("José 😀\n", read_all(&mut source).unwrap());
+	assert_eq!(11, source.offset());
+	assert_eq!(None, source.next_char().unwrap());
+}
+
+#[test]
+fn from_reader_split_characters() {
+	let text: String = "ñandú 日本 😀".repeat(3000);
+	let mut source: Source = Source::from_reader(Trickle(text.clone().into_bytes()));
+	assert_eq!(text, read_all(&mut source).unwrap());
+
+	// Characters straddling the internal buffer are kept too.
+	let mut source: Source = Source::from_reader(std::io::Cursor::new(text.clone().into_bytes()));
+	assert_eq!(text, read_all(&mut source).unwrap());
+	assert_eq!(text.len(), source.offset());
+}
+
+#[test]
+fn from_bytes_offsets() {
+	let mut source: Source = Source::from_bytes("aé\n日".as_bytes());
+	let mut offsets: Vec<(char, usize, usize, usize)> = Vec::new();
+
+	loop {
+		let (offset, position): (usize, Position) = (source.offset(), source.position());
+		match source.next_char().unwrap() {
+			Some(c) => offsets.push((c, offset, position.line, position.column)),
+			None => break,
+		}
+	}
+
+	assert_eq!(vec![('a', 0, 0, 0), ('é', 1, 0, 1), ('\n', 3, 0, 2), ('日', 4, 1, 0)], offsets);
+}
+
+#[test]
+fn invalid_utf8() {
+	let mut source: Source = Source::from_bytes(b"ab\n\xffc");
+	assert_eq!(Some('a'), source.next_char().unwrap());
+	assert_eq!(Some('b'), source.next_char().unwrap());
+	assert_eq!(Some('\n'), source.next_char().unwrap());
+
+	let exception: Exception = source.next_char().err().unwrap();
+	assert_eq!("invalid UTF-8 byte 0xff at byte offset 3", exception.except.message);
+	assert_eq!(Position::new(0, 1), source.position());
+
+	let mut source: Source = Source::from_bytes(b"a\xe6\x97");
+	let exception: Exception = read_all(&mut source).err().unwrap();
+	assert_eq!("incomplete UTF-8 character at byte offset 1", exception.except.message);
+}
+
+#[test]
+fn invalid_utf8_in_lexer() {
+	let module: &str = "tests/source/invalid_utf8";
+
+	for bytes in [&b"let x = 1\nlet \xc3(y)"[..], &b"\"text \xff\""[..]] {
+		let mut lexer: Lexer = Lexer::new(Source::from_bytes(bytes), module);
+		let exception: Exception = lexer.run().err().unwrap();
+
+		assert!(exception.except.message.starts_with("invalid UTF-8 byte"));
+		assert_eq!(module, exception.exception_points[0].module_context);
+	}
+
+	let mut lexer: Lexer = Lexer::new(Source::from_bytes(b"let x = 1\nlet \xc3(y)"), module);
+	let exception: Exception = lexer.run().err().unwrap();
+	assert_eq!(Position::new(4, 1), exception.exception_points[0].position);
+}